@@ -15,18 +15,24 @@
 //! ```
 
 use std::ops::{Deref, DerefMut};
+use std::marker::PhantomData;
 use std::io;
 use std::iter::FromIterator;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
-use rocket::request::Request;
+use rocket::request::{Request, Form, FromForm, FormDataError};
 use rocket::outcome::Outcome::*;
-use rocket::data::{Data, ByteUnit, Transform::*, Transformed};
-use rocket::data::{FromTransformedData, TransformFuture, FromDataFuture};
+use rocket::data::{self, Data, DataStream, ByteUnit, LimitExceeded, Transform::*, Transformed};
+use rocket::data::{FromData, FromTransformedData, TransformFuture, FromDataFuture};
 use rocket::http::Status;
 use rocket::response::{self, Responder, content};
+use rocket::futures::stream::{self, Stream};
+use rocket::tokio::io::{AsyncReadExt, AsyncBufReadExt, BufReader};
+use rocket::tokio::sync::mpsc::Sender;
 
 use serde::{Serialize, Serializer};
-use serde::de::{Deserialize, Deserializer};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
 
 #[doc(hidden)]
 pub use serde_json::{json_internal, json_internal_vec};
@@ -190,6 +196,499 @@ impl<T> DerefMut for Json<T> {
     }
 }
 
+/// A data guard that parses a [`FromForm`] type from either a form or a flat
+/// JSON object.
+///
+/// `JsonForm<T>` lets a single route accept both encodings of the same data.
+/// If the request's `Content-Type` is JSON, the body must be a JSON object
+/// whose values are strings, numbers, booleans, or `null`. Each value is
+/// coerced to a string and the object is adapted into a form, which is then
+/// parsed strictly, exactly as [`Form<T>`](rocket::request::Form) would parse
+/// it. Members with a `null` value are omitted. Otherwise, the request is
+/// handled exactly as by `Form<T>`.
+///
+/// Because coercing JSON values to strings loses type information (`"1"` and
+/// `1` are the same field value), this adaptation is never applied by `Form<T>`
+/// or `Json<T>`; it must be requested explicitly by using `JsonForm<T>`.
+///
+/// JSON bodies are limited by the `json` limit, which defaults to 1MiB. A JSON
+/// body over the limit fails with status `PayloadTooLarge` and
+/// [`FormDataError::TooLarge`](rocket::request::FormDataError::TooLarge).
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # extern crate rocket_contrib;
+/// use rocket_contrib::json::JsonForm;
+///
+/// #[derive(FromForm)]
+/// struct Task {
+///     description: String,
+///     done: bool,
+/// }
+///
+/// // Accepts both `description=Write&done=false` and
+/// // `{ "description": "Write", "done": false }`.
+/// #[post("/tasks", data = "<task>")]
+/// fn new_task(task: JsonForm<Task>) -> String {
+///     format!("{} ({})", task.description, task.done)
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct JsonForm<T>(pub T);
+
+impl<T> JsonForm<T> {
+    /// Consumes the wrapper and returns the wrapped item.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for JsonForm<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for JsonForm<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// An error returned by the [`JsonForm`] data guard.
+#[derive(Debug)]
+pub enum JsonFormError<'a, E> {
+    /// Reading the body or parsing the form failed.
+    Form(FormDataError<'a, E>),
+
+    /// The body is not a valid JSON object.
+    Json(serde_json::error::Error),
+
+    /// The value of the member with the key in `.0` is an array or an object.
+    NotFlat(String),
+}
+
+/// Adapts a flat JSON object into a form string.
+fn json_to_form<'a, E>(json: &str) -> Result<String, (Status, JsonFormError<'a, E>)> {
+    use serde_json::{Map, Value};
+    use rocket::http::uri::Uri;
+
+    let object: Map<String, Value> = serde_json::from_str(json).map_err(|e| {
+        error_!("Couldn't parse JSON body as an object: {:?}", e);
+        let status = if e.is_data() { Status::UnprocessableEntity } else { Status::BadRequest };
+        (status, JsonFormError::Json(e))
+    })?;
+
+    let mut fields = Vec::with_capacity(object.len());
+    for (key, value) in object {
+        let value = match value {
+            Value::Null => continue,
+            Value::String(string) => string,
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::Array(_) | Value::Object(_) => {
+                error_!("JSON member {:?} is an array or object.", key);
+                return Err((Status::UnprocessableEntity, JsonFormError::NotFlat(key)));
+            }
+        };
+
+        fields.push(format!("{}={}", Uri::percent_encode(&key), Uri::percent_encode(&value)));
+    }
+
+    Ok(fields.join("&"))
+}
+
+impl<'a, T: FromForm<'a> + Send + 'a> FromTransformedData<'a> for JsonForm<T> {
+    type Error = JsonFormError<'a, T::Error>;
+    type Owned = String;
+    type Borrowed = str;
+
+    fn transform(r: &'a Request<'_>, d: Data) -> TransformFuture<'a, Self::Owned, Self::Error> {
+        Box::pin(async move {
+            if !r.content_type().map_or(false, |ct| ct.is_json()) {
+                let form_error = |(status, e)| (status, JsonFormError::Form(e));
+                return match <Form<T>>::transform(r, d).await {
+                    Owned(outcome) => Owned(outcome.map_failure(form_error)),
+                    Borrowed(outcome) => Borrowed(outcome.map_failure(form_error)),
+                };
+            }
+
+            let size_limit = r.limits().get("json").unwrap_or(DEFAULT_LIMIT);
+            let json = match d.open_within(size_limit).stream_to_string().await {
+                Ok(json) => json,
+                Err(e) if LimitExceeded::of(&e).is_some() => {
+                    error_!("JSON form data exceeds limit ({}).", size_limit);
+                    let err = FormDataError::TooLarge(size_limit.as_u64().saturating_add(1));
+                    return Borrowed(Failure((Status::PayloadTooLarge, JsonFormError::Form(err))));
+                }
+                Err(e) => {
+                    let err = JsonFormError::Form(FormDataError::Io(e));
+                    return Borrowed(Failure((Status::BadRequest, err)));
+                }
+            };
+
+            match json_to_form(&json) {
+                Ok(form) => Borrowed(Success(form)),
+                Err(e) => Borrowed(Failure(e)),
+            }
+        })
+    }
+
+    fn from_data(r: &'a Request<'_>, o: Transformed<'a, Self>) -> FromDataFuture<'a, Self, Self::Error> {
+        Box::pin(async move {
+            let string = try_outcome!(o.borrowed());
+            <Form<T>>::from_data(r, Borrowed(Success(string))).await
+                .map(|form| JsonForm(form.into_inner()))
+                .map_failure(|(status, e)| (status, JsonFormError::Form(e)))
+        })
+    }
+}
+
+/// A data guard that incrementally deserializes the elements of a top-level
+/// JSON array.
+///
+/// Unlike [`Json<Vec<T>>`](Json), which reads the entire request body into
+/// memory before deserializing it, `JsonStream<T>` is a [`Stream`] that reads
+/// and deserializes one array element at a time. Only the bytes of the current
+/// element are buffered, making it suitable for consuming large arrays.
+///
+/// The total number of bytes read is limited by the `json` limit, which
+/// defaults to 1MiB. If the array is not closed before the limit is reached,
+/// the stream yields a [`JsonStreamError::Incomplete`] error. Any data
+/// following the array's closing bracket is ignored.
+///
+/// An element that is valid JSON but fails to deserialize as `T` yields a
+/// [`JsonStreamError::Parse`] error; the stream then continues with the next
+/// element. All other errors end the stream.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # extern crate rocket_contrib;
+/// # type User = usize;
+/// use rocket::futures::stream::StreamExt;
+/// use rocket_contrib::json::JsonStream;
+///
+/// #[post("/users", format = "json", data = "<users>")]
+/// async fn new_users(mut users: JsonStream<User>) -> String {
+///     let mut count = 0;
+///     while let Some(user) = users.next().await {
+///         match user {
+///             Ok(_user) => count += 1,
+///             Err(e) => return format!("error: {:?}", e),
+///         }
+///     }
+///
+///     format!("added {} users", count)
+/// }
+/// # fn main() { }
+/// ```
+pub struct JsonStream<T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T, JsonStreamError>> + Send>>,
+}
+
+/// An error returned by a [`JsonStream`] while reading array elements.
+#[derive(Debug)]
+pub enum JsonStreamError {
+    /// An I/O error occurred while reading the incoming request data.
+    Io(io::Error),
+
+    /// The request body is not a JSON array.
+    Syntax,
+
+    /// The request body ended before the array was closed. This occurs when
+    /// the body is truncated or exceeds the `json` limit.
+    Incomplete,
+
+    /// An element failed to parse as valid JSON or as the requested type.
+    Parse(serde_json::error::Error),
+}
+
+impl From<io::Error> for JsonStreamError {
+    fn from(error: io::Error) -> Self {
+        JsonStreamError::Io(error)
+    }
+}
+
+/// Splits a stream of bytes containing a JSON array into its raw elements.
+struct ArrayReader {
+    stream: DataStream,
+    buf: Vec<u8>,
+    pos: usize,
+    started: bool,
+    done: bool,
+}
+
+impl ArrayReader {
+    const CHUNK_SIZE: usize = 4096;
+
+    async fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        if self.pos == self.buf.len() {
+            self.buf.resize(Self::CHUNK_SIZE, 0);
+            let n = self.stream.read(&mut self.buf).await?;
+            self.buf.truncate(n);
+            self.pos = 0;
+            if n == 0 {
+                return Ok(None);
+            }
+        }
+
+        self.pos += 1;
+        Ok(Some(self.buf[self.pos - 1]))
+    }
+
+    async fn next_non_whitespace(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.next_byte().await? {
+                Some(b) if b.is_ascii_whitespace() => continue,
+                byte => return Ok(byte),
+            }
+        }
+    }
+
+    /// Returns the raw bytes of the next element, or `None` when the end of
+    /// the array is reached.
+    async fn next_element(&mut self) -> Result<Option<Vec<u8>>, JsonStreamError> {
+        if self.done {
+            return Ok(None);
+        }
+
+        if !self.started {
+            self.started = true;
+            match self.next_non_whitespace().await? {
+                Some(b'[') => {},
+                Some(_) => return Err(JsonStreamError::Syntax),
+                None => return Err(JsonStreamError::Incomplete),
+            }
+
+            match self.next_non_whitespace().await? {
+                Some(b']') => { self.done = true; return Ok(None); }
+                // The byte begins the first element; leave it to be reread.
+                Some(_) => self.pos -= 1,
+                None => return Err(JsonStreamError::Incomplete),
+            }
+        }
+
+        let mut element = vec![];
+        let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+        loop {
+            let byte = self.next_byte().await?.ok_or(JsonStreamError::Incomplete)?;
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+            } else {
+                match byte {
+                    b'"' => in_string = true,
+                    b'[' | b'{' => depth += 1,
+                    b']' | b'}' if depth > 0 => depth -= 1,
+                    b',' if depth == 0 => break,
+                    b']' if depth == 0 => { self.done = true; break; }
+                    _ => {}
+                }
+            }
+
+            element.push(byte);
+        }
+
+        if element.iter().all(|b| b.is_ascii_whitespace()) {
+            return Err(JsonStreamError::Syntax);
+        }
+
+        Ok(Some(element))
+    }
+}
+
+impl<T: DeserializeOwned + Send + 'static> JsonStream<T> {
+    fn new(data: DataStream) -> Self {
+        let reader = ArrayReader { stream: data, buf: vec![], pos: 0, started: false, done: false };
+        let inner = stream::unfold(reader, |mut reader| async move {
+            let item = match reader.next_element().await {
+                Ok(Some(bytes)) => serde_json::from_slice(&bytes).map_err(JsonStreamError::Parse),
+                Ok(None) => return None,
+                Err(e) => {
+                    reader.done = true;
+                    Err(e)
+                }
+            };
+
+            Some((item, reader))
+        });
+
+        JsonStream { inner: Box::pin(inner) }
+    }
+}
+
+impl<T> Stream for JsonStream<T> {
+    type Item = Result<T, JsonStreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Always succeeds. The request body is read lazily as the stream is polled.
+#[rocket::async_trait]
+impl<T: DeserializeOwned + Send + 'static> FromData for JsonStream<T> {
+    type Error = std::convert::Infallible;
+
+    async fn from_data(r: &Request<'_>, d: Data) -> data::Outcome<Self, Self::Error> {
+        let size_limit = r.limits().get("json").unwrap_or(DEFAULT_LIMIT);
+        Success(JsonStream::new(d.open(size_limit)))
+    }
+}
+
+/// A data guard that deserializes newline-delimited JSON (NDJSON) into a
+/// channel.
+///
+/// `NdJson<T>` reads the request body one line at a time, deserializes each
+/// non-empty line as a `T`, and sends it through the
+/// [`Sender<Result<T, JsonStreamError>>`](Sender) in managed state as soon as
+/// the line arrives. A line that fails to deserialize is sent as a
+/// [`JsonStreamError::Parse`] error, and reading continues with the next line.
+/// An I/O error is sent as a [`JsonStreamError::Io`] error and ends reading.
+/// The guard succeeds once the body has been consumed, at which point every
+/// item has been sent. This allows handlers to feed a pipeline running on
+/// another task, which holds the receiving half of the channel.
+///
+/// The total number of bytes read is limited by the `json` limit, which
+/// defaults to 1MiB.
+///
+/// # Failure
+///
+/// If no `Sender<Result<T, JsonStreamError>>` is managed, the guard fails with
+/// status `InternalServerError` and [`NdJsonError::Unmanaged`] without reading
+/// the body. If the receiving half of the channel is closed, reading stops,
+/// and the guard fails with status `ServiceUnavailable` and
+/// [`NdJsonError::Closed`]. If the body exceeds the `json` limit, reading
+/// stops, the line that crossed the limit is discarded, and the guard fails
+/// with status `PayloadTooLarge` and [`NdJsonError::TooLarge`]. The lines
+/// before it have already been sent.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # extern crate rocket_contrib;
+/// # type Event = usize;
+/// use rocket::tokio::sync::mpsc::channel;
+/// use rocket_contrib::json::{NdJson, JsonStreamError};
+///
+/// #[post("/events", data = "<events>")]
+/// fn ingest(events: NdJson<Event>) -> String {
+///     format!("queued {} events ({} invalid)", events.count(), events.errors())
+/// }
+///
+/// async fn rocket() -> rocket::Rocket {
+///     let (tx, mut rx) = channel::<Result<Event, JsonStreamError>>(128);
+///     rocket::tokio::spawn(async move {
+///         while let Some(_event) = rx.recv().await {
+///             /* process the event */
+///         }
+///     });
+///
+///     rocket::ignite().manage(tx).mount("/", routes![ingest])
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct NdJson<T> {
+    count: usize,
+    errors: usize,
+    _item: PhantomData<fn() -> T>,
+}
+
+/// An error returned by the [`NdJson`] data guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NdJsonError {
+    /// No `Sender<Result<T, JsonStreamError>>` is in managed state.
+    Unmanaged,
+
+    /// The receiving half of the channel was closed before the request body
+    /// was consumed.
+    Closed,
+
+    /// The request body exceeded the `json` limit, `.0`.
+    TooLarge(ByteUnit),
+}
+
+impl<T> NdJson<T> {
+    /// Returns the number of lines that were deserialized and sent as `Ok`.
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of errors that were sent as `Err`.
+    #[inline(always)]
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+}
+
+/// Sends each line of the body through the managed channel. See [`NdJson`]
+/// for details on failure.
+#[rocket::async_trait]
+impl<T: DeserializeOwned + Send + 'static> FromData for NdJson<T> {
+    type Error = NdJsonError;
+
+    async fn from_data(r: &Request<'_>, d: Data) -> data::Outcome<Self, Self::Error> {
+        let sender = match r.managed_state::<Sender<Result<T, JsonStreamError>>>() {
+            Some(sender) => sender,
+            None => {
+                error_!("Missing managed NDJSON channel for `{}`.", std::any::type_name::<T>());
+                return Failure((Status::InternalServerError, NdJsonError::Unmanaged));
+            }
+        };
+
+        let size_limit = r.limits().get("json").unwrap_or(DEFAULT_LIMIT);
+        let mut reader = BufReader::new(d.open_within(size_limit));
+        let (mut count, mut errors, mut line) = (0, 0, vec![]);
+        loop {
+            line.clear();
+            let item = match reader.read_until(b'\n', &mut line).await {
+                Ok(0) => break,
+                Ok(_) if line.iter().all(|b| b.is_ascii_whitespace()) => continue,
+                Ok(_) => serde_json::from_slice(&line).map_err(JsonStreamError::Parse),
+                Err(e) if LimitExceeded::of(&e).is_some() => {
+                    error_!("NDJSON data exceeds limit ({}).", size_limit);
+                    return Failure((Status::PayloadTooLarge, NdJsonError::TooLarge(size_limit)));
+                }
+                Err(e) => Err(JsonStreamError::Io(e)),
+            };
+
+            let is_io_error = matches!(item, Err(JsonStreamError::Io(_)));
+            match item.is_ok() {
+                true => count += 1,
+                false => errors += 1,
+            }
+
+            if sender.send(item).await.is_err() {
+                warn_!("NDJSON channel closed before the request body was consumed.");
+                return Failure((Status::ServiceUnavailable, NdJsonError::Closed));
+            }
+
+            if is_io_error {
+                break;
+            }
+        }
+
+        Success(NdJson { count, errors, _item: PhantomData })
+    }
+}
+
 /// An arbitrary JSON value.
 ///
 /// This structure wraps `serde`'s [`Value`] type. Importantly, unlike `Value`,
@@ -17,9 +17,10 @@
 use std::path::{PathBuf, Path};
 
 use rocket::{Request, Data, Route};
-use rocket::http::{Method, uri::Segments, ext::IntoOwned};
+use rocket::http::{Method, ContentType, uri::Segments, ext::IntoOwned};
 use rocket::handler::{Handler, Outcome};
-use rocket::response::{NamedFile, Redirect};
+use rocket::request::AcceptsEncoding;
+use rocket::response::{self, NamedFile, Redirect, Responder};
 
 /// Generates a crate-relative version of `$path`.
 ///
@@ -68,6 +69,8 @@ pub use crate_relative;
 ///   * [`Options::Index`] - Render `index.html` pages for directory requests.
 ///   * [`Options::NormalizeDirs`] - Redirect directories without a trailing
 ///     slash to ones with a trailing slash.
+///   * [`Options::Precompressed`] - Serve precompressed `.br` or `.gz` siblings
+///     of requested files to clients that accept them.
 ///
 /// `Options` structures can be `or`d together to select two or more options.
 /// For instance, to request that both dot files and index pages be returned,
@@ -104,6 +107,19 @@ impl Options {
     /// directory, rather than its parent. This is _not_ enabled by default.
     pub const NormalizeDirs: Options = Options(0b0100);
 
+    /// `Options` enabling serving precompressed files.
+    ///
+    /// When enabled, the [`StaticFiles`] handler will respond to a request for
+    /// a file `${root}/foo.js` with the file `${root}/foo.js.br` if the
+    /// request's `Accept-Encoding` header accepts `br` and the file exists,
+    /// or, failing that, with `${root}/foo.js.gz` if `gzip` is accepted and the
+    /// file exists. The response's `Content-Type` is that of the requested
+    /// file and its `Content-Encoding` is set accordingly. Otherwise, the
+    /// requested file is served as usual. A precompressed file is never served
+    /// if the requested file itself doesn't exist. This is _not_ enabled by
+    /// default.
+    pub const Precompressed: Options = Options(0b1000);
+
     /// Returns `true` if `self` is a superset of `other`. In other words,
     /// returns `true` if all of the options in `other` are also in `self`.
     ///
@@ -346,10 +362,79 @@ async fn handle_dir<'r, P>(opt: Options, r: &'r Request<'_>, d: Data, p: P) -> O
         return Outcome::forward(d);
     }
 
-    let file = NamedFile::open(p.as_ref().join("index.html")).await.ok();
+    let file = StaticFile::open(opt, r, &p.as_ref().join("index.html")).await;
     Outcome::from_or_forward(r, d, file)
 }
 
+/// A file served by `StaticFiles`, possibly a precompressed variant of the
+/// requested file.
+struct StaticFile {
+    file: NamedFile,
+    precompressed: Option<(&'static str, Option<ContentType>)>,
+    vary: bool,
+}
+
+/// The supported precompressed encodings, in order of preference, and the
+/// file extensions of the corresponding files.
+const ENCODINGS: &[(&str, &str)] = &[("br", "br"), ("gzip", "gz")];
+
+/// Returns the encodings in `ENCODINGS` accepted by the request, ordered by
+/// the client's quality values. Ties keep the order of `ENCODINGS`.
+fn accepted_encodings(req: &Request<'_>) -> Vec<(&'static str, &'static str, f32)> {
+    let accepts = AcceptsEncoding::parse(req.headers().get("Accept-Encoding"));
+    let mut encodings: Vec<_> = ENCODINGS.iter()
+        .map(|&(encoding, ext)| (encoding, ext, accepts.quality(encoding)))
+        .filter(|&(_, _, quality)| quality > 0.0)
+        .collect();
+
+    encodings.sort_by(|a, b| b.2.partial_cmp(&a.2).expect("q-values are finite"));
+    encodings
+}
+
+impl StaticFile {
+    async fn open(opt: Options, req: &Request<'_>, path: &Path) -> Option<StaticFile> {
+        let vary = opt.contains(Options::Precompressed);
+        if vary && path.is_file() {
+            for (encoding, ext, _) in accepted_encodings(req) {
+                let mut compressed = path.as_os_str().to_owned();
+                compressed.push(".");
+                compressed.push(ext);
+                if let Ok(file) = NamedFile::open(&compressed).await {
+                    let content_type = path.extension()
+                        .and_then(|ext| ext.to_str())
+                        .and_then(ContentType::from_extension);
+
+                    let precompressed = Some((encoding, content_type));
+                    return Some(StaticFile { file, precompressed, vary });
+                }
+            }
+        }
+
+        let file = NamedFile::open(path).await.ok()?;
+        Some(StaticFile { file, precompressed: None, vary })
+    }
+}
+
+impl<'r> Responder<'r, 'static> for StaticFile {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let mut response = self.file.respond_to(req)?;
+        if let Some((encoding, content_type)) = self.precompressed {
+            response.remove_header("Content-Type");
+            if let Some(content_type) = content_type {
+                response.set_header(content_type);
+            }
+
+            response.set_raw_header("Content-Encoding", encoding);
+        }
+
+        if self.vary {
+            response.set_raw_header("Vary", "Accept-Encoding");
+        }
+
+        Ok(response)
+    }
+}
+
 #[rocket::async_trait]
 impl Handler for StaticFiles {
     async fn handle<'r, 's: 'r>(&'s self, req: &'r Request<'_>, data: Data) -> Outcome<'r> {
@@ -371,7 +456,10 @@ impl Handler for StaticFiles {
 
         match path {
             Some(p) if p.is_dir() => handle_dir(self.options, req, data, p).await,
-            Some(p) => Outcome::from_or_forward(req, data, NamedFile::open(p).await.ok()),
+            Some(p) => {
+                let file = StaticFile::open(self.options, req, &p).await;
+                Outcome::from_or_forward(req, data, file)
+            }
             None => Outcome::forward(data),
         }
     }
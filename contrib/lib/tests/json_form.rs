@@ -0,0 +1,91 @@
+#[macro_use]
+#[cfg(feature = "json")]
+extern crate rocket;
+
+#[cfg(feature = "json")]
+mod json_form_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+
+    use rocket_contrib::json::JsonForm;
+
+    #[derive(FromForm)]
+    struct Task {
+        description: String,
+        done: bool,
+        priority: Option<u8>,
+    }
+
+    #[post("/", data = "<task>")]
+    fn new_task(task: JsonForm<Task>) -> String {
+        format!("{}: {} ({:?})", task.description, task.done, task.priority)
+    }
+
+    fn post(content_type: ContentType, body: &str) -> (Status, Option<String>) {
+        post_with(rocket::ignite(), content_type, body)
+    }
+
+    fn post_with(
+        rocket: rocket::Rocket,
+        content_type: ContentType,
+        body: &str
+    ) -> (Status, Option<String>) {
+        let client = Client::tracked(rocket.mount("/", routes![new_task])).unwrap();
+        let response = client.post("/").header(content_type).body(body).dispatch();
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn flat_json_objects_are_adapted() {
+        let body = r#"{ "description": "Write a & b = c?", "done": true, "priority": 3 }"#;
+        let (status, body) = post(ContentType::JSON, body);
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "Write a & b = c?: true (Some(3))");
+
+        let body = r#"{ "description": "100% done", "done": false, "priority": null }"#;
+        let (_, body) = post(ContentType::JSON, body);
+        assert_eq!(body.unwrap(), "100% done: false (None)");
+    }
+
+    #[test]
+    fn forms_are_parsed_as_usual() {
+        let (status, body) = post(ContentType::Form, "description=Write&done=false&priority=1");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "Write: false (Some(1))");
+    }
+
+    #[test]
+    fn invalid_json_objects_fail() {
+        let (status, _) = post(ContentType::JSON, r#"{ "description": "Write", "done": "#);
+        assert_eq!(status, Status::BadRequest);
+
+        let (status, _) = post(ContentType::JSON, r#"["Write", true]"#);
+        assert_eq!(status, Status::UnprocessableEntity);
+
+        let body = r#"{ "description": ["Write"], "done": true }"#;
+        let (status, _) = post(ContentType::JSON, body);
+        assert_eq!(status, Status::UnprocessableEntity);
+
+        // The adapted form is parsed strictly.
+        let body = r#"{ "description": "Write", "done": true, "extra": 1 }"#;
+        let (status, _) = post(ContentType::JSON, body);
+        assert_eq!(status, Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn json_bodies_over_the_limit_are_rejected() {
+        use rocket::data::Limits;
+
+        let body = r#"{ "description": "Write", "done": true }"#;
+        let limits = Limits::default().limit("json", (body.len() as u64 - 1).into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        let (status, _) = post_with(rocket::custom(config), ContentType::JSON, body);
+        assert_eq!(status, Status::PayloadTooLarge);
+
+        let limits = Limits::default().limit("json", (body.len() as u64).into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        let (status, body) = post_with(rocket::custom(config), ContentType::JSON, body);
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "Write: true (None)");
+    }
+}
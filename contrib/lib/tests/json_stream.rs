@@ -0,0 +1,80 @@
+#[macro_use]
+#[cfg(feature = "json")]
+extern crate rocket;
+
+#[cfg(feature = "json")]
+mod json_stream_tests {
+    use rocket::futures::stream::StreamExt;
+    use rocket::local::blocking::Client;
+    use rocket::data::Limits;
+
+    use rocket_contrib::json::{JsonStream, JsonStreamError, JsonValue};
+
+    #[post("/", data = "<items>")]
+    async fn sum(mut items: JsonStream<JsonValue>) -> String {
+        let (mut count, mut total) = (0, 0);
+        while let Some(item) = items.next().await {
+            match item {
+                Ok(item) => {
+                    let id = item["id"].as_u64().unwrap();
+                    assert_eq!(item["name"], format!("item [{}], \"{}\"", id, id));
+                    count += 1;
+                    total += id;
+                }
+                Err(JsonStreamError::Parse(_)) => return format!("parse error at {}", count),
+                Err(JsonStreamError::Incomplete) => return "incomplete".into(),
+                Err(e) => return format!("error: {:?}", e),
+            }
+        }
+
+        format!("{} items, total {}", count, total)
+    }
+
+    fn body(n: u64) -> String {
+        let items: Vec<_> = (0..n)
+            .map(|i| format!(r#"{{ "id": {}, "name": "item [{}], \"{}\"" }}"#, i, i, i))
+            .collect();
+
+        format!("[\n  {}\n]", items.join(",\n  "))
+    }
+
+    fn client(limit: u64) -> Client {
+        let limits = Limits::default().limit("json", limit.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        Client::tracked(rocket::custom(config).mount("/", routes![sum])).unwrap()
+    }
+
+    #[test]
+    fn streams_elements() {
+        let client = client(1 << 20);
+        let response = client.post("/").body(body(10_000)).dispatch();
+        assert_eq!(response.into_string().unwrap(), "10000 items, total 49995000");
+
+        let response = client.post("/").body(" [ ] ").dispatch();
+        assert_eq!(response.into_string().unwrap(), "0 items, total 0");
+    }
+
+    #[test]
+    fn reports_bad_elements() {
+        let client = client(1 << 20);
+        let body = r#"[{ "id": 0, "name": "item [0], \"0\"" }, { "id": one }]"#;
+        let response = client.post("/").body(body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "parse error at 1");
+
+        let response = client.post("/").body(r#"{ "id": 0 }"#).dispatch();
+        assert_eq!(response.into_string().unwrap(), "error: Syntax");
+
+        let response = client.post("/").body("[1, , 2]").dispatch();
+        assert_eq!(response.into_string().unwrap(), "error: Syntax");
+    }
+
+    #[test]
+    fn enforces_limit() {
+        let body = body(100);
+        let response = client(body.len() as u64 - 1).post("/").body(&body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "incomplete");
+
+        let response = client(body.len() as u64).post("/").body(&body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "100 items, total 4950");
+    }
+}
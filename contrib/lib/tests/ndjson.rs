@@ -0,0 +1,84 @@
+#[macro_use]
+#[cfg(feature = "json")]
+extern crate rocket;
+
+#[cfg(feature = "json")]
+mod ndjson_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::tokio::sync::mpsc::{channel, Receiver};
+
+    use rocket_contrib::json::{NdJson, JsonStreamError, JsonValue};
+
+    type Item = Result<JsonValue, JsonStreamError>;
+
+    #[post("/", data = "<items>")]
+    fn ingest(items: NdJson<JsonValue>) -> String {
+        format!("{} items, {} errors", items.count(), items.errors())
+    }
+
+    fn client() -> (Client, Receiver<Item>) {
+        let (tx, rx) = channel::<Item>(16);
+        let rocket = rocket::ignite().manage(tx).mount("/", routes![ingest]);
+        (Client::tracked(rocket).unwrap(), rx)
+    }
+
+    #[test]
+    fn sends_each_line() {
+        let (client, mut rx) = client();
+        let body = "{ \"id\": 1 }\n{ \"id\": 2 }\r\n\n  \n{ \"id\": 3 }";
+        let response = client.post("/").body(body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "3 items, 0 errors");
+
+        for id in 1..=3 {
+            let item = rx.blocking_recv().unwrap().unwrap();
+            assert_eq!(item["id"].as_u64(), Some(id));
+        }
+    }
+
+    #[test]
+    fn sends_line_errors() {
+        let (client, mut rx) = client();
+        let body = "{ \"id\": 1 }\n{ \"id\": one }\n[1, 2\n{ \"id\": 4 }\n";
+        let response = client.post("/").body(body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "2 items, 2 errors");
+
+        assert_eq!(rx.blocking_recv().unwrap().unwrap()["id"].as_u64(), Some(1));
+        assert!(matches!(rx.blocking_recv().unwrap(), Err(JsonStreamError::Parse(_))));
+        assert!(matches!(rx.blocking_recv().unwrap(), Err(JsonStreamError::Parse(_))));
+        assert_eq!(rx.blocking_recv().unwrap().unwrap()["id"].as_u64(), Some(4));
+    }
+
+    #[test]
+    fn fails_when_too_large() {
+        let (tx, mut rx) = channel::<Item>(16);
+        let limits = rocket::data::Limits::default().limit("json", 16.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        let rocket = rocket::custom(config).manage(tx.clone()).mount("/", routes![ingest]);
+        let client = Client::tracked(rocket).unwrap();
+
+        let response = client.post("/").body("{ \"id\": 1 }\n{ \"id\": 2 }\n").dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        // The line that crossed the limit is not sent, truncated or otherwise.
+        tx.blocking_send(Err(JsonStreamError::Syntax)).unwrap();
+        assert_eq!(rx.blocking_recv().unwrap().unwrap()["id"].as_u64(), Some(1));
+        assert!(matches!(rx.blocking_recv().unwrap(), Err(JsonStreamError::Syntax)));
+    }
+
+    #[test]
+    fn fails_without_channel() {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![ingest])).unwrap();
+        let response = client.post("/").body("{ \"id\": 1 }\n").dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+    }
+
+    #[test]
+    fn fails_when_channel_closed() {
+        let (client, rx) = client();
+        drop(rx);
+
+        let response = client.post("/").body("{ \"id\": 1 }\n").dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+    }
+}
@@ -5,7 +5,7 @@ mod static_tests {
 
     use rocket::{self, Rocket, Route};
     use rocket_contrib::serve::{StaticFiles, Options, crate_relative};
-    use rocket::http::Status;
+    use rocket::http::{Status, Header, ContentType};
     use rocket::local::blocking::Client;
 
     fn static_root() -> &'static Path {
@@ -22,6 +22,7 @@ mod static_tests {
             .mount("/both", StaticFiles::new(&root, Options::DotFiles | Options::Index))
             .mount("/redir", StaticFiles::new(&root, Options::NormalizeDirs))
             .mount("/redir_index", StaticFiles::new(&root, Options::NormalizeDirs | Options::Index))
+            .mount("/compressed", StaticFiles::new(&root, Options::Precompressed))
     }
 
     static REGULAR_FILES: &[&str] = &[
@@ -189,4 +190,68 @@ mod static_tests {
         assert_eq!(response.status(), Status::PermanentRedirect);
         assert_eq!(response.headers().get("Location").next(), Some("/redir_index/"));
     }
+
+    #[test]
+    fn test_precompressed() {
+        let client = Client::tracked(rocket()).expect("valid rocket");
+        let get = |prefix: &str, accept: Option<&'static str>| {
+            let mut request = client.get(format!("/{}/compressed/hello.txt", prefix));
+            if let Some(accept) = accept {
+                request.add_header(Header::new("Accept-Encoding", accept));
+            }
+
+            let response = request.dispatch();
+            assert_eq!(response.status(), Status::Ok);
+            assert_eq!(response.content_type(), Some(ContentType::Plain));
+            let encoding = response.headers().get_one("Content-Encoding").map(String::from);
+            (encoding, response.into_string().unwrap())
+        };
+
+        let (encoding, body) = get("compressed", Some("gzip, deflate, br"));
+        assert_eq!(encoding.as_deref(), Some("br"));
+        assert_eq!(body, "brotli: Hello, world!\n");
+
+        let (encoding, body) = get("compressed", Some("gzip"));
+        assert_eq!(encoding.as_deref(), Some("gzip"));
+        assert_eq!(body, "gzip: Hello, world!\n");
+
+        let (encoding, body) = get("compressed", Some("br;q=0, gzip;q=0.5"));
+        assert_eq!(encoding.as_deref(), Some("gzip"));
+        assert_eq!(body, "gzip: Hello, world!\n");
+
+        let (encoding, body) = get("compressed", Some("gzip, br;q=0.5"));
+        assert_eq!(encoding.as_deref(), Some("gzip"));
+        assert_eq!(body, "gzip: Hello, world!\n");
+
+        let (encoding, body) = get("compressed", Some("*"));
+        assert_eq!(encoding.as_deref(), Some("br"));
+        assert_eq!(body, "brotli: Hello, world!\n");
+
+        let (encoding, body) = get("compressed", Some("*, br;q=0"));
+        assert_eq!(encoding.as_deref(), Some("gzip"));
+        assert_eq!(body, "gzip: Hello, world!\n");
+
+        let rejected = [Some("br;q=0"), Some("*;q=0"), Some("gzip;q=0, br;q=0.0")];
+        for accept in [None, Some("identity")].iter().chain(&rejected) {
+            let (encoding, body) = get("compressed", *accept);
+            assert_eq!(encoding, None);
+            assert_eq!(body, "Hello, world!\n");
+        }
+
+        // Without the option, the plain file is always served.
+        let (encoding, body) = get("default", Some("gzip, br"));
+        assert_eq!(encoding, None);
+        assert_eq!(body, "Hello, world!\n");
+
+        // Precompressed files are only served in place of existing files.
+        let response = client.get("/compressed/compressed/hello.txt.br").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert!(response.headers().get_one("Content-Encoding").is_none());
+
+        let response = client.get("/compressed/compressed/missing.txt")
+            .header(Header::new("Accept-Encoding", "br"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
 }
@@ -23,6 +23,7 @@ struct RouteAttribute {
     path: RoutePath,
     data: Option<SpanWrapped<DataSegment>>,
     format: Option<MediaType>,
+    assume_format: Option<SpanWrapped<bool>>,
     rank: Option<isize>,
 }
 
@@ -33,6 +34,7 @@ struct MethodRouteAttribute {
     path: RoutePath,
     data: Option<SpanWrapped<DataSegment>>,
     format: Option<MediaType>,
+    assume_format: Option<SpanWrapped<bool>>,
     rank: Option<isize>,
 }
 
@@ -66,6 +68,14 @@ fn parse_route(attr: RouteAttribute, function: syn::ItemFn) -> Result<Route> {
         }
     }
 
+    // An assumed format is meaningless without a format to assume.
+    if let Some(ref assume) = attr.assume_format {
+        if assume.value && attr.format.is_none() {
+            diags.push(assume.full_span.error("`assume_format` requires a `format`")
+                .help("declare the format to assume with `format = \"...\"`"));
+        }
+    }
+
     // Collect non-wild dynamic segments in an `IndexSet`, checking for dups.
     let mut segments: IndexSet<Segment> = IndexSet::new();
     fn dup_check<'a, I>(set: &mut IndexSet<Segment>, iter: I, diags: &mut Diagnostics)
@@ -419,6 +429,7 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
     let path = route.attribute.path.origin.0.to_string();
     let rank = Optional(route.attribute.rank);
     let format = Optional(route.attribute.format);
+    let assume_format = route.attribute.assume_format.map_or(false, |a| a.value);
 
     Ok(quote! {
         #user_handler_fn
@@ -450,6 +461,7 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
                     path: #path,
                     handler: monomorphized_function,
                     format: #format,
+                    assume_format: #assume_format,
                     rank: #rank,
                 }
             }
@@ -513,6 +525,7 @@ fn incomplete_route(
         path: method_attribute.path,
         data: method_attribute.data,
         format: method_attribute.format,
+        assume_format: method_attribute.assume_format,
         rank: method_attribute.rank,
     };
 
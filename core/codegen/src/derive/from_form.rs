@@ -1,11 +1,46 @@
 use devise::{*, ext::{TypeExt, Split3, SpanDiagnosticExt}};
 
 use crate::proc_macro2::{Span, TokenStream};
-use crate::syn_ext::NameSource;
+use crate::syn_ext::{NameSource, IdentExt};
 
-#[derive(FromMeta)]
+#[derive(Default, FromMeta)]
 pub struct Form {
-    pub field: FormField,
+    pub field: Option<FormField>,
+    pub transform: Option<ExprString>,
+    pub parse_with: Option<ExprString>,
+    pub decimal: Option<DecimalSeparator>,
+    pub default_if: Option<SpanWrapped<String>>,
+    pub format: Option<SpanWrapped<String>>,
+    pub trim: Option<SpanWrapped<bool>>,
+    pub default: Option<ExprString>,
+    pub default_with: Option<ExprString>,
+    pub compute: Option<ExprString>,
+}
+
+/// The container-level `#[form(...)]` attribute.
+#[derive(Default, FromMeta)]
+struct FormOptions {
+    capture_unknown: Option<SpanWrapped<String>>,
+    tag: Option<SpanWrapped<String>>,
+    allowed: Option<FieldList>,
+    require: Option<FieldList>,
+}
+
+/// The variant-level `#[form(...)]` attribute of a tagged enum or of an enum
+/// deriving `FromFormValue` or `UriDisplayQuery`.
+#[derive(FromMeta)]
+pub struct VariantForm {
+    pub value: NameSource,
+}
+
+impl VariantForm {
+    /// Returns the form value of `variant`: the value set via
+    /// `#[form(value = "...")]`, if any, or the variant's identifier.
+    pub fn value_of(variant: &Variant<'_>) -> Result<NameSource> {
+        VariantForm::from_attrs("form", &variant.attrs)
+            .unwrap_or_else(|| Ok(VariantForm { value: variant.ident.clone().into() }))
+            .map(|form| form.value)
+    }
 }
 
 pub struct FormField {
@@ -13,6 +48,71 @@ pub struct FormField {
     pub name: NameSource,
 }
 
+/// The decimal separator of a numeric field: `decimal = ","`.
+pub struct DecimalSeparator {
+    pub span: Span,
+    pub comma: bool,
+}
+
+/// A comma-separated list of form field names: `key = "a, b, c"`.
+pub struct FieldList {
+    pub span: Span,
+    pub names: Vec<String>,
+}
+
+/// A Rust expression written as a string literal: `key = "expr"`.
+pub struct ExprString {
+    pub span: Span,
+    pub expr: crate::syn::Expr,
+}
+
+impl Form {
+    /// Returns the name of the form field corresponding to `field`: the name
+    /// set via `#[form(field = "...")]`, if any, or the field's identifier.
+    pub fn name_of(field: &Field<'_>) -> Result<NameSource> {
+        let default_name = NameSource::from(field.ident.clone().expect("named"));
+        let form = match Form::from_attrs("form", &field.attrs) {
+            Some(form) => form?,
+            None => return Ok(default_name),
+        };
+
+        Ok(form.field.map(|field| field.name).unwrap_or(default_name))
+    }
+
+    /// Returns the expression that computes `field`, set via
+    /// `#[form(compute = "...")]`, if any. Computed fields can't be combined
+    /// with any other field option.
+    pub fn compute_of(field: &Field<'_>) -> Result<Option<ExprString>> {
+        let form = match Form::from_attrs("form", &field.attrs) {
+            Some(form) => form?,
+            None => return Ok(None),
+        };
+
+        let compute = match form.compute {
+            Some(compute) => compute,
+            None => return Ok(None),
+        };
+
+        let others = [
+            form.field.map(|field| field.span),
+            form.transform.map(|transform| transform.span),
+            form.parse_with.map(|parse_with| parse_with.span),
+            form.decimal.map(|decimal| decimal.span),
+            form.default_if.map(|default_if| default_if.full_span),
+            form.format.map(|format| format.full_span),
+            form.trim.map(|trim| trim.full_span),
+            form.default.map(|default| default.span),
+            form.default_with.map(|default_with| default_with.span),
+        ];
+
+        match others.iter().flatten().next() {
+            Some(span) => Err(span.error("computed fields accept no other options")
+                .span_note(compute.span, "field is computed here")),
+            None => Ok(Some(compute)),
+        }
+    }
+}
+
 fn is_valid_field_name(s: &str) -> bool {
     // The HTML5 spec (4.10.18.1) says 'isindex' is not allowed.
     if s == "isindex" || s.is_empty() {
@@ -35,19 +135,167 @@ impl FromMeta for FormField {
     }
 }
 
-fn validate_struct(_: &DeriveGenerator, data: Struct<'_>) -> Result<()> {
-    if data.fields().is_empty() {
-        return Err(data.fields.span().error("at least one field is required"));
+impl FromMeta for DecimalSeparator {
+    fn from_meta(meta: MetaItem<'_>) -> Result<Self> {
+        let comma = match String::from_meta(meta)?.as_str() {
+            "," => true,
+            "." => false,
+            _ => return Err(meta.value_span().error("invalid decimal separator")
+                .help("the separator must be either `\",\"` or `\".\"`")),
+        };
+
+        Ok(DecimalSeparator { span: meta.value_span(), comma })
+    }
+}
+
+impl FromMeta for FieldList {
+    fn from_meta(meta: MetaItem<'_>) -> Result<Self> {
+        let string = String::from_meta(meta)?;
+        let names: Vec<_> = string.split(',').map(|s| s.trim().to_string()).collect();
+        for (i, name) in names.iter().enumerate() {
+            if !is_valid_field_name(name) {
+                return Err(meta.value_span().error(format!("invalid form field name: {:?}", name))
+                    .help("names must be separated by commas: `\"a, b, c\"`"));
+            }
+
+            if names[..i].contains(name) {
+                return Err(meta.value_span().error(format!("duplicate form field name: {:?}", name)));
+            }
+        }
+
+        Ok(FieldList { span: meta.value_span(), names })
+    }
+}
+
+impl FromMeta for ExprString {
+    fn from_meta(meta: MetaItem<'_>) -> Result<Self> {
+        let string = String::from_meta(meta)?;
+        let expr = crate::syn::parse_str(&string)
+            .map_err(|e| meta.value_span().error(format!("invalid expression: {}", e)))?;
+
+        Ok(ExprString { span: meta.value_span(), expr })
     }
+}
+
+impl quote::ToTokens for ExprString {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let expr = &self.expr;
+        tokens.extend(quote_spanned!(self.span => (#expr)));
+    }
+}
+
+/// Checks that a string literal passed to `rocket::request::matching()` in a
+/// `parse_with` option is a valid regular expression, so that an invalid
+/// pattern is a compile-time error rather than a panic when the form is parsed.
+/// Only calls through that path are checked: the derive can't resolve other
+/// paths, which may name an unrelated `matching()`. Only done when Rocket's
+/// `regex` feature, which enables this crate's, is enabled.
+#[cfg(feature = "regex")]
+fn check_pattern(parse_with: &ExprString) -> Result<()> {
+    use crate::syn::{Expr, ExprLit, Lit};
 
+    let call = match &parse_with.expr {
+        Expr::Call(call) => call,
+        _ => return Ok(()),
+    };
+
+    let is_matching = match &*call.func {
+        Expr::Path(path) => {
+            let segments: Vec<_> = path.path.segments.iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+
+            path.qself.is_none() && segments == ["rocket", "request", "matching"]
+        }
+        _ => false,
+    };
+
+    match call.args.first() {
+        Some(Expr::Lit(ExprLit { lit: Lit::Str(pattern), .. })) if is_matching => {
+            regex::Regex::new(&pattern.value())
+                .map(|_| ())
+                .map_err(|e| parse_with.span.error(format!("invalid pattern: {}", e)))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if `ty` is a `PhantomData`. Such fields aren't parsed from
+/// the form; they're always set to their default value.
+fn is_phantom_data(ty: &crate::syn::Type) -> bool {
+    match ty {
+        crate::syn::Type::Path(path) => path.path.segments.last()
+            .map_or(false, |segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `ty` is or contains a reference or a lifetime, and so may
+/// borrow from the form.
+fn borrows(ty: &crate::syn::Type) -> bool {
+    fn any_punct(tokens: TokenStream) -> bool {
+        use crate::proc_macro2::TokenTree;
+
+        tokens.into_iter().any(|tree| match tree {
+            TokenTree::Punct(ref p) => p.as_char() == '&' || p.as_char() == '\'',
+            TokenTree::Group(group) => any_punct(group.stream()),
+            _ => false,
+        })
+    }
+
+    any_punct(quote!(#ty))
+}
+
+/// Returns `true` if `tokens` contain the identifier `ident`.
+fn mentions(tokens: TokenStream, ident: &crate::syn::Ident) -> bool {
+    use crate::proc_macro2::TokenTree;
+
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ref i) => i == ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the type parameter `ident` is used by a field of `input`
+/// other than a `PhantomData`. Only such parameters need to implement
+/// `FromFormValue`.
+fn is_parsed_generic(input: &crate::syn::DeriveInput, ident: &crate::syn::Ident) -> bool {
+    use crate::syn::Data;
+    use quote::ToTokens;
+
+    let fields: Vec<&crate::syn::Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        Data::Union(_) => vec![],
+    };
+
+    fields.into_iter()
+        .filter(|field| !is_phantom_data(&field.ty))
+        .any(|field| mentions(field.ty.to_token_stream(), ident))
+}
+
+fn validate_fields(fields: Fields<'_>, tag: Option<&str>) -> Result<()> {
     let mut names = ::std::collections::HashMap::new();
-    for field in data.fields().iter() {
+    for field in fields.iter().filter(|field| !is_phantom_data(&field.ty)) {
+        if Form::compute_of(&field)?.is_some() {
+            continue;
+        }
+
         let id = field.ident.as_ref().expect("named field");
-        let field = match Form::from_attrs("form", &field.attrs) {
+        let renamed = match Form::from_attrs("form", &field.attrs) {
             Some(result) => result?.field,
-            None => FormField { span: Spanned::span(&id), name: id.clone().into() }
+            None => None,
         };
 
+        let field = renamed.unwrap_or_else(|| {
+            FormField { span: Spanned::span(&id), name: id.clone().into() }
+        });
+
+        if tag.map_or(false, |tag| field.name.name() == tag) {
+            return Err(field.span.error("field name conflicts with the enum's tag"));
+        }
+
         if let Some(span) = names.get(&field.name) {
             return Err(field.span.error("duplicate field name")
                        .span_note(*span, "previous definition here"));
@@ -59,20 +307,552 @@ fn validate_struct(_: &DeriveGenerator, data: Struct<'_>) -> Result<()> {
     Ok(())
 }
 
-pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
+fn validate_struct(_: &DeriveGenerator, data: Struct<'_>) -> Result<()> {
+    if data.fields().is_empty() {
+        return Err(data.fields.span().error("at least one field is required"));
+    }
+
+    let options = FormOptions::from_attrs("form", &data.derive_input.attrs)
+        .unwrap_or_else(|| Ok(Default::default()))?;
+
+    if let Some(tag) = options.tag {
+        return Err(tag.full_span.error("`tag` is only supported on enums"));
+    }
+
+    validate_fields(data.fields(), None)
+}
+
+fn validate_enum(_: &DeriveGenerator, data: Enum<'_>) -> Result<()> {
+    let options = FormOptions::from_attrs("form", &data.derive_input.attrs)
+        .unwrap_or_else(|| Ok(Default::default()))?;
+
+    if let Some(capture) = options.capture_unknown {
+        return Err(capture.full_span.error("`capture_unknown` is not supported on enums"));
+    }
+
+    if let Some(require) = options.require {
+        return Err(require.span.error("`require` is not supported on enums"));
+    }
+
+    let tag = match options.tag {
+        Some(tag) => tag,
+        None => return Err(data.derive_input.ident.span()
+            .error("enums must declare a tag field")
+            .help("add a container attribute: `#[form(tag = \"...\")]`")),
+    };
+
+    if data.variants.is_empty() {
+        return Err(data.brace_token.span.error("enum must have at least one variant"));
+    }
+
+    let mut values = ::std::collections::HashMap::new();
+    for variant in data.variants() {
+        if variant.fields().are_unnamed() {
+            return Err(variant.fields().span().error("tuple variants are not supported"));
+        }
+
+        let value = VariantForm::value_of(&variant)?;
+        let span = variant.ident.span();
+        if let Some(previous) = values.insert(value.name().to_lowercase(), span) {
+            return Err(span.error("duplicate tag value")
+                       .span_note(previous, "previous definition here"));
+        }
+
+        validate_fields(variant.fields(), Some(&tag.value))?;
+    }
+
+    Ok(())
+}
+
+/// Generates code that parses the fields in `fields` from `__items` and then
+/// constructs the value with the struct or variant path `builder`. If `tag` is
+/// set, keys named `tag` are ignored. If `allowed` is set, keys not in the list
+/// are rejected, even in lenient mode, while listed keys that don't name a
+/// field are accepted, even in strict mode. If `require` is set, the listed
+/// fields that are missing are reported together in a single error. `generics`
+/// are the generics of the type being derived for.
+fn fields_parser(
+    fields: Fields<'_>,
+    generics: &crate::syn::Generics,
+    builder: TokenStream,
+    capture_unknown: Option<SpanWrapped<String>>,
+    allowed: Option<&FieldList>,
+    require: Option<&FieldList>,
+    tag: Option<&str>,
+) -> Result<TokenStream> {
+    define_vars_and_mods!(_None, _Some, _Ok, _Err, _Option);
+    let form_error = quote!(::rocket::request::FormParseError);
+
+    // The field, if any, that collects unknown keys and their values.
+    let capture = match capture_unknown {
+        Some(name) => {
+            let field = fields.iter().find(|f| f.ident.as_ref().unwrap() == &name.value);
+            Some(field.ok_or_else(|| name.span.error("unknown field")
+                .help("`capture_unknown` must name a field of the structure"))?)
+        }
+        None => None,
+    };
+
+    let is_capture = |field: &Field<'_>| {
+        capture.as_ref().map_or(false, |c| c.ident == field.ident)
+    };
+
+    // The fields computed from the others, which are never parsed.
+    let mut computed = vec![];
+    for field in fields.iter().filter(|field| !is_capture(field)) {
+        if let Some(compute) = Form::compute_of(&field)? {
+            computed.push((field, compute));
+        }
+    }
+
+    let is_computed = |field: &Field<'_>| computed.iter().any(|(c, _)| c.ident == field.ident);
+
+    let phantom_builders = fields.iter()
+        .filter(|field| !is_capture(field) && !is_computed(field))
+        .filter(|field| is_phantom_data(&field.ty))
+        .map(|field| {
+            let (ident, span) = (&field.ident, field.span());
+            quote_spanned!(span => #ident: ::std::default::Default::default(),)
+        });
+
+    let regular = fields.iter()
+        .filter(|field| !is_capture(field) && !is_computed(field))
+        .filter(|field| !is_phantom_data(&field.ty));
+
+    let mut field_names = vec![];
+    let (constructors, matchers, builders) = regular.map(|field| {
+        let (ident, span) = (&field.ident, field.span());
+        let name = Form::name_of(&field)?;
+        let form = Form::from_attrs("form", &field.attrs).transpose()?.unwrap_or_default();
+        let Form {
+            transform, parse_with, decimal, default_if, format, trim, default, default_with, ..
+        } = form;
+
+        // A decimal point is `FromFormValue`'s default; only commas need work.
+        let decimal_comma = match decimal {
+            Some(decimal) if transform.is_some() || parse_with.is_some() || format.is_some() => {
+                return Err(decimal.span.error("`decimal` cannot be combined with \
+                    `transform`, `parse_with`, or `format`"));
+            }
+            Some(decimal) => decimal.comma,
+            None => false,
+        };
+
+        if let (Some(default), Some(default_with)) = (&default, &default_with) {
+            return Err(default_with.span.error("`default_with` cannot be combined with `default`")
+                .span_note(default.span, "the default is set here"));
+        }
+
+        let has_default = default.is_some() || default_with.is_some();
+        if let (Some(sentinel), Some(parse_with), false) = (&default_if, &parse_with, has_default) {
+            return Err(sentinel.full_span.error("`default_if` cannot be combined with `parse_with` \
+                    unless a default is set")
+                .span_note(parse_with.span, "fields parsed with `parse_with` have no default")
+                .help("set a default with `default` or `default_with`"));
+        }
+
+        if let (Some(format), Some(parse_with)) = (&format, &parse_with) {
+            return Err(format.full_span.error("`format` cannot be combined with `parse_with`")
+                .span_note(parse_with.span, "the field is parsed with `parse_with` here"));
+        }
+
+        #[cfg(feature = "regex")]
+        if let Some(parse_with) = &parse_with {
+            check_pattern(parse_with)?;
+        }
+
+        // Trimmed and transformed values are owned by the parser, so the
+        // field can't borrow them from the form.
+        if parse_with.is_none() && format.is_none() && borrows(&field.ty) {
+            let modifier = match (&transform, &trim) {
+                (Some(transform), _) => Some(("transform", transform.span)),
+                (None, Some(trim)) if trim.value => Some(("trim", trim.full_span)),
+                _ => None,
+            };
+
+            if let Some((modifier, modifier_span)) = modifier {
+                return Err(field.ty.span().error(format!("`{}` cannot be used with a field \
+                        that borrows from the form", modifier))
+                    .span_note(modifier_span, format!("`{}` is applied here", modifier))
+                    .help("use an owned type such as `String`"));
+            }
+        }
+
+        // Formatted fields are parsed, and defaulted, by `FromFormattedValue`.
+        let ty = field.ty.with_stripped_lifetimes();
+        let ty = match format {
+            Some(ref format) => quote_spanned! {
+                format.span => <#ty as ::rocket::request::FromFormattedValue>
+            },
+            None => quote_spanned! {
+                span => <#ty as ::rocket::request::FromFormValue>
+            },
+        };
+
+        // A `parse_with` parser is built once, the first time any form of this
+        // type is parsed, and kept in a `static`. The `static` can't name the
+        // type's lifetimes or generic parameters, so the field's type can't
+        // either.
+        let parser = ident.as_ref().expect("named").prepend("__parse_");
+        let constructor = match &parse_with {
+            Some(parse_with) => {
+                use quote::ToTokens;
+
+                let generic = generics.type_params()
+                    .any(|param| mentions(field.ty.to_token_stream(), &param.ident));
+
+                if generic || borrows(&field.ty) {
+                    return Err(field.ty.span().error("`parse_with` cannot be used with a field \
+                            whose type has lifetimes or generic parameters")
+                        .span_note(parse_with.span, "the field is parsed with `parse_with` here"));
+                }
+
+                let field_ty = &field.ty;
+                quote_spanned! { span =>
+                    let mut #ident = #_None;
+                    let #parser = {
+                        #[allow(non_upper_case_globals)]
+                        static __parser: ::rocket::Lazy<::std::boxed::Box<
+                            dyn Fn(&str) -> #_Option<#field_ty> + Send + Sync
+                        >> = ::rocket::Lazy::new();
+
+                        __parser.get_or_init(|| {
+                            let __parse = #parse_with;
+                            ::std::boxed::Box::new(move |__v: &str| -> #_Option<#field_ty> {
+                                __parse(__v).ok()
+                            })
+                        })
+                    };
+                }
+            }
+            None => quote_spanned!(span => let mut #ident = #_None;),
+        };
+
+        let name = name.name();
+        field_names.push(name.to_string());
+        // Whitespace is trimmed from the decoded value before anything else
+        // sees it, including any transform.
+        let trim = trim.map_or(false, |trim| trim.value);
+        let trim_decoded = match trim {
+            true => Some(quote_spanned!(span => let __decoded = __decoded.trim();)),
+            false => None,
+        };
+
+        let decode = quote_spanned! { span =>
+            let __decoded = __v.url_decode()
+                .map_err(|_| #form_error::BadValue(__k, __v))?;
+            #trim_decoded
+        };
+
+        // The field's default, if any, as an `Option`. A custom default takes
+        // precedence over the type's; fields parsed with `parse_with` have no
+        // other default.
+        let default = match (default, default_with) {
+            (Some(default), _) => quote_spanned!(default.span => #_Some(#default)),
+            (_, Some(default_with)) => quote_spanned!(default_with.span => #_Some(#default_with())),
+            (None, None) if parse_with.is_some() => quote_spanned!(span => #_None),
+            (None, None) => quote_spanned!(span => #ty::default()),
+        };
+
+        let body = match (transform, parse_with) {
+            // Formatted values are parsed from the decoded, and possibly
+            // transformed, value with the field's format string.
+            (transform, None) if format.is_some() => {
+                let format = format.as_ref().map(|format| &format.value);
+                let transform = transform.map(|transform| quote_spanned! { span =>
+                    let __decoded = #transform(&*__decoded);
+                });
+
+                quote_spanned! { span =>
+                    #decode
+                    #transform
+                    #ident = #_Some(#ty::parse_with_format(&*__decoded, #format)
+                        .map_err(|_| #form_error::BadValue(__k, __v))?);
+                }
+            }
+            // Values must use a decimal comma, which is replaced with a point
+            // before the value is re-encoded and parsed with `FromFormValue`.
+            (None, None) if decimal_comma => quote_spanned! { span =>
+                #decode
+                if __decoded.contains('.') {
+                    return #_Err(#form_error::BadValue(__k, __v));
+                }
+
+                let __normalized = __decoded.replacen(',', ".", 1);
+                let __encoded = ::rocket::http::uri::Uri::percent_encode(&*__normalized);
+                let __value = ::rocket::http::RawStr::from_str(&*__encoded);
+                #ident = #_Some(#ty::from_form_value(__value)
+                    .map_err(|_| #form_error::BadValue(__k, __v))?);
+            },
+            // Custom parsers operate on the decoded, and possibly
+            // transformed, value. The field's type need not implement
+            // `FromFormValue`.
+            (transform, Some(_)) => {
+                let transform = transform.map(|transform| quote_spanned! { span =>
+                    let __decoded = #transform(&*__decoded);
+                });
+
+                quote_spanned! { span =>
+                    #decode
+                    #transform
+                    #ident = #_Some(#parser(&*__decoded)
+                        .ok_or_else(|| #form_error::BadValue(__k, __v))?);
+                }
+            }
+            // Transforms operate on the decoded value. The transformed
+            // value is re-encoded so that `FromFormValue` sees it as it
+            // would any other form value.
+            (Some(transform), None) => quote_spanned! { span =>
+                #decode
+                let __transformed = #transform(&*__decoded);
+                let __encoded = ::rocket::http::uri::Uri::percent_encode(&*__transformed);
+                let __value = ::rocket::http::RawStr::from_str(&*__encoded);
+                #ident = #_Some(#ty::from_form_value(__value)
+                    .map_err(|_| #form_error::BadValue(__k, __v))?);
+            },
+            // Trimmed values are re-encoded like transformed values.
+            (None, None) if trim => quote_spanned! { span =>
+                #decode
+                let __encoded = ::rocket::http::uri::Uri::percent_encode(__decoded);
+                let __value = ::rocket::http::RawStr::from_str(&*__encoded);
+                #ident = #_Some(#ty::from_form_value(__value)
+                    .map_err(|_| #form_error::BadValue(__k, __v))?);
+            },
+            (None, None) => quote_spanned! { span =>
+                #ident = #_Some(#ty::from_form_value(__v)
+                    .map_err(|_| #form_error::BadValue(__k, __v))?);
+            },
+        };
+
+        // A submitted sentinel value resets the field to its default, if any.
+        // The sentinel is compared with the decoded, and possibly trimmed,
+        // value, before any transformation is applied to it.
+        let sentinel = default_if.map(|sentinel| {
+            let value = &sentinel.value;
+            let decoded = match trim {
+                true => quote_spanned!(sentinel.span => __decoded.trim()),
+                false => quote_spanned!(sentinel.span => __decoded),
+            };
+
+            quote_spanned! { sentinel.span =>
+                if __v.url_decode().map_or(false, |__decoded| #decoded == #value) {
+                    #ident = #default;
+                    continue;
+                }
+            }
+        });
+
+        let matcher = quote_spanned!(span => #name => { #sentinel #body },);
+
+        let builder = quote_spanned! { span =>
+            let #ident = #ident.or_else(|| #default)
+                .ok_or_else(|| #form_error::Missing(#name.into()))?;
+        };
+
+        Ok((constructor, matcher, builder))
+    }).collect::<Result<Vec<_>>>()?.into_iter().split3();
+
+    // The tag of a tagged enum is handled by the enum's parser.
+    let tag_matcher = tag.map(|tag| quote!(#tag => { /* the variant's tag */ }));
+
+    let (capture_constructor, capture_matcher) = match capture {
+        Some(field) => {
+            let (ident, span) = (&field.ident, field.span());
+            let ty = field.ty.with_stripped_lifetimes();
+            let constructor = quote_spanned! { span =>
+                let mut #ident: #ty = ::std::default::Default::default();
+            };
+
+            let matcher = quote_spanned! { span =>
+                _ if __k != "_method" => {
+                    let __bad_value = |_| #form_error::BadValue(__k, __v);
+                    let __key = __k.url_decode().map_err(__bad_value)?;
+                    let __value = __v.url_decode().map_err(__bad_value)?;
+                    ::std::iter::Extend::extend(&mut #ident, #_Some((__key, __value)));
+                }
+            };
+
+            (Some(constructor), Some(matcher))
+        }
+        None => (None, None),
+    };
+
+    // Required fields are checked together, before their defaults are applied.
+    // Each missing field's index in the `require` list is pushed to `__missing`.
+    let required_check = match require {
+        Some(list) => {
+            if list.names.len() > 64 {
+                return Err(list.span.error(format!("`require` lists {} fields, but at most \
+                        64 fields can be required", list.names.len()))
+                    .help("list only the fields that must be submitted together"));
+            }
+
+            let mut checks = vec![];
+            for (i, name) in list.names.iter().enumerate() {
+                let field = fields.iter()
+                    .filter(|field| !is_capture(field) && !is_computed(field))
+                    .filter(|field| !is_phantom_data(&field.ty))
+                    .find(|field| Form::name_of(field).map_or(false, |n| n.name() == name))
+                    .ok_or_else(|| list.span.error(format!("unknown form field: {:?}", name))
+                        .help("`require` must only list parsed fields of the structure"))?;
+
+                let (ident, index) = (&field.ident, i as u8);
+                checks.push(quote_spanned! { list.span =>
+                    if #ident.is_none() { __missing.push(#index); }
+                });
+            }
+
+            let names = &list.names;
+            Some(quote_spanned! { list.span =>
+                let mut __missing = ::rocket::request::RequiredFields::new(&[#(#names),*]);
+                #(#checks)*
+                if !__missing.is_empty() {
+                    return #_Err(#form_error::MissingRequired(__missing));
+                }
+            })
+        }
+        None => None,
+    };
+
+    // Computed fields see every parsed field, and the captured field, by
+    // reference, in the order they're declared.
+    let parsed_idents: Vec<_> = fields.iter()
+        .filter(|field| !is_computed(field) && !is_phantom_data(&field.ty))
+        .map(|field| field.ident.clone())
+        .collect();
+
+    let computers = computed.iter().map(|(field, compute)| {
+        let (ident, span) = (&field.ident, field.span());
+        let ty = field.ty.with_stripped_lifetimes();
+        quote_spanned! { span =>
+            let #ident: #ty = {
+                #(#[allow(unused_variables)] let #parsed_idents = &#parsed_idents;)*
+                #compute
+            };
+        }
+    });
+
+    let field_idents = fields.iter()
+        .filter(|field| !is_phantom_data(&field.ty) || is_computed(field))
+        .map(|field| &field.ident);
+
+    // With an allow-list, keys outside of the list are rejected outright, and
+    // listed keys that aren't fields are accepted even when parsing strictly.
+    let (allowed_check, allowed_matcher) = match allowed {
+        Some(list) => {
+            // `_method` and the tag are always allowed.
+            let names: Vec<_> = list.names.iter()
+                .filter(|name| *name != "_method" && Some(name.as_str()) != tag)
+                .collect();
+
+            let tag = tag.into_iter();
+            let check = quote_spanned! { list.span =>
+                match __k.as_str() {
+                    "_method" #(| #names)* #(| #tag)* => { /* allowed */ }
+                    _ => return #_Err(#form_error::Unknown(__k, __v)),
+                }
+            };
+
+            let extras: Vec<_> = names.iter()
+                .filter(|name| !field_names.contains(name))
+                .collect();
+
+            let matcher = match extras.is_empty() {
+                true => None,
+                false => Some(quote_spanned! { list.span =>
+                    #(#extras)|* => { /* allowed, but not a field */ }
+                }),
+            };
+
+            (Some(check), matcher)
+        }
+        None => (None, None),
+    };
+
+    Ok(quote! {
+        #(#constructors)*
+        #capture_constructor
+
+        for (__k, __v) in __items.map(|item| item.key_value()) {
+            #allowed_check
+
+            match __k.as_str() {
+                #tag_matcher
+                #(#matchers)*
+                #capture_matcher
+                #allowed_matcher
+                _ if __strict && __k != "_method" => {
+                    return #_Err(#form_error::Unknown(__k, __v));
+                }
+                _ => { /* lenient or "method"; let it pass */ }
+            }
+        }
+
+        #required_check
+        #(#builders)*
+        #(#computers)*
+        #_Ok(#builder { #(#field_idents,)* #(#phantom_builders)* })
+    })
+}
+
+/// Generates code that finds the value of the tag field in `__items` and then
+/// parses the fields of the corresponding variant.
+fn enum_parser(data: Enum<'_>) -> Result<TokenStream> {
+    define_vars_and_mods!(_Ok, _Err);
     let form_error = quote!(::rocket::request::FormParseError);
+    let options = FormOptions::from_attrs("form", &data.derive_input.attrs)
+        .unwrap_or_else(|| Ok(Default::default()))?;
+
+    let allowed = options.allowed.as_ref();
+    let tag = options.tag.expect("validated tag").value;
+    let enum_name = &data.derive_input.ident;
+    let (mut values, mut arms) = (vec![], vec![]);
+    for variant in data.variants() {
+        let value = VariantForm::value_of(&variant)?;
+        let value = value.name();
+        let variant_name = &variant.ident;
+        let builder = quote!(#enum_name::#variant_name);
+        let generics = &data.derive_input.generics;
+        let fields = variant.fields();
+        let parser = fields_parser(fields, generics, builder, None, allowed, None, Some(&tag))?;
+        arms.push(quote!(__tag if __tag == #value => { #parser }));
+        values.push(value.to_string());
+    }
+
+    Ok(quote! {
+        let __tag = __items.clone()
+            .map(|item| item.key_value())
+            .find(|(__k, _)| __k.as_str() == #tag);
+
+        let (__k, __v) = match __tag {
+            Some(key_value) => key_value,
+            None => return #_Err(#form_error::Missing(#tag.into())),
+        };
+
+        // The tag's value is compared after URL-decoding, like any other value.
+        let __tag_value = __v.url_decode_lossy();
+        match ::rocket::http::uncased::UncasedStr::new(&__tag_value) {
+            #(#arms)*
+            _ => #_Err(#form_error::UnknownTag(__k, __v, &[#(#values),*])),
+        }
+    })
+}
+
+pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
     DeriveGenerator::build_for(input, quote!(impl<'__f> ::rocket::request::FromForm<'__f>))
         .generic_support(GenericSupport::Lifetime | GenericSupport::Type)
         .replace_generic(0, 0)
-        .data_support(DataSupport::NamedStruct)
-        .map_type_generic(|_, ident, _| quote! {
-            #ident : ::rocket::request::FromFormValue<'__f>
+        .data_support(DataSupport::NamedStruct | DataSupport::Enum)
+        .map_type_generic(|gen, ident, _| match is_parsed_generic(&gen.input, ident) {
+            true => quote!(#ident : ::rocket::request::FromFormValue<'__f>),
+            false => quote!(#ident : Sized),
         })
         .validate_generics(|_, generics| match generics.lifetimes().enumerate().last() {
             Some((i, lt)) if i >= 1 => Err(lt.span().error("only one lifetime is supported")),
             _ => Ok(())
         })
         .validate_struct(validate_struct)
+        .validate_enum(validate_enum)
         .function(|_, inner| quote! {
             type Error = ::rocket::request::FormParseError<'__f>;
 
@@ -83,51 +863,14 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
                 #inner
             }
         })
-        .try_map_fields(move |_, fields| {
-            define_vars_and_mods!(_None, _Some, _Ok, _Err);
-            let (constructors, matchers, builders) = fields.iter().map(|field| {
-                let (ident, span) = (&field.ident, field.span());
-                let default_name = NameSource::from(ident.clone().expect("named"));
-                let name = Form::from_attrs("form", &field.attrs)
-                    .map(|result| result.map(|form| form.field.name))
-                    .unwrap_or_else(|| Ok(default_name))?;
-
-                let ty = field.ty.with_stripped_lifetimes();
-                let ty = quote_spanned! {
-                    span => <#ty as ::rocket::request::FromFormValue>
-                };
-
-                let constructor = quote_spanned!(span => let mut #ident = #_None;);
-
-                let name = name.name();
-                let matcher = quote_spanned! { span =>
-                    #name => { #ident = #_Some(#ty::from_form_value(__v)
-                                .map_err(|_| #form_error::BadValue(__k, __v))?); },
-                };
-
-                let builder = quote_spanned! { span =>
-                    #ident: #ident.or_else(#ty::default)
-                        .ok_or_else(|| #form_error::Missing(#name.into()))?,
-                };
-
-                Ok((constructor, matcher, builder))
-            }).collect::<Result<Vec<_>>>()?.into_iter().split3();
-
-            Ok(quote! {
-                #(#constructors)*
-
-                for (__k, __v) in __items.map(|item| item.key_value()) {
-                    match __k.as_str() {
-                        #(#matchers)*
-                        _ if __strict && __k != "_method" => {
-                            return #_Err(#form_error::Unknown(__k, __v));
-                        }
-                        _ => { /* lenient or "method"; let it pass */ }
-                    }
-                }
+        .try_map_fields(|gen, fields| {
+            let options = FormOptions::from_attrs("form", fields.parent.attrs())
+                .unwrap_or_else(|| Ok(Default::default()))?;
 
-                #_Ok(Self { #(#builders)* })
-            })
+            let (allowed, require) = (options.allowed.as_ref(), options.require.as_ref());
+            let (generics, capture) = (&gen.input.generics, options.capture_unknown);
+            fields_parser(fields, generics, quote!(Self), capture, allowed, require, None)
         })
+        .try_map_enum(|_, data| enum_parser(data))
         .to_tokens2()
 }
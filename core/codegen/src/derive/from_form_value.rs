@@ -1,16 +1,28 @@
+use std::{cell::RefCell, rc::Rc};
+
 use devise::{*, ext::SpanDiagnosticExt};
 
+use crate::derive::from_form::VariantForm;
 use crate::proc_macro2::TokenStream;
-use crate::syn_ext::NameSource;
 
-#[derive(FromMeta)]
-struct Form {
-    value: NameSource,
+/// The container-level `#[form(...)]` attribute.
+#[derive(Default, FromMeta)]
+struct FormValueOptions {
+    from_str: bool,
+    numeric: bool,
+    default: Option<SpanWrapped<String>>,
 }
 
 pub fn derive_from_form_value(input: proc_macro::TokenStream) -> TokenStream {
-    define_vars_and_mods!(_Ok, _Err, _Result);
-    DeriveGenerator::build_for(input, quote!(impl<'__v> ::rocket::request::FromFormValue<'__v>))
+    define_vars_and_mods!(_Ok, _Err, _Result, _Some);
+    let trait_name = quote!(impl<'__v> ::rocket::request::FromFormValue<'__v>);
+
+    // The inherent `as_str()` method is built from the same variant values as
+    // the trait implementation and emitted after it.
+    let as_str = Rc::new(RefCell::new(None));
+    let as_str_impl = as_str.clone();
+
+    let from_form_value = DeriveGenerator::build_for(input, trait_name)
         .generic_support(GenericSupport::None)
         .data_support(DataSupport::Enum)
         .validate_enum(|_, data| {
@@ -29,31 +41,120 @@ pub fn derive_from_form_value(input: proc_macro::TokenStream) -> TokenStream {
             Ok(())
         })
         .function(move |_, inner| quote! {
-            type Error = &'__v ::rocket::http::RawStr;
-
-            fn from_form_value(
-                value: &'__v ::rocket::http::RawStr
-            ) -> #_Result<Self, Self::Error> {
-                let uncased = value.as_uncased_str();
-                #inner
-                #_Err(value)
-            }
+            type Error = ::rocket::request::UnknownValue<'__v>;
+
+            #inner
         })
-        .try_map_enum(null_enum_mapper)
-        .try_map_variant(|_, variant| {
-            define_vars_and_mods!(_Ok);
-            let variant_name_source = Form::from_attrs("form", &variant.attrs)
-                .unwrap_or_else(|| Ok(Form { value: variant.ident.clone().into() }))?
-                .value;
+        .try_map_enum(move |_, data| {
+            let enum_name = &data.derive_input.ident;
+            let (mut values, mut arms, mut numeric_arms) = (vec![], vec![], vec![]);
+            let mut as_str_arms = vec![];
+            for variant in data.variants() {
+                let variant_name_source = VariantForm::value_of(&variant)?;
+                let variant_str = variant_name_source.name();
+                let builder = variant.builder(|_| unreachable!("no fields"));
+                arms.push(quote! {
+                    if uncased == #variant_str {
+                        return #_Ok(#builder);
+                    }
+                });
+
+                numeric_arms.push(quote! {
+                    if __n == #builder as i128 {
+                        return #_Ok(#builder);
+                    }
+                });
+
+                let variant_name = &variant.ident;
+                as_str_arms.push(quote!(#enum_name::#variant_name => #variant_str,));
+
+                values.push(variant_str.to_string());
+            }
+
+            *as_str_impl.borrow_mut() = Some(quote! {
+                impl #enum_name {
+                    /// Returns the canonical form value of `self`: the string
+                    /// that parses as this variant.
+                    #[allow(dead_code)]
+                    pub fn as_str(&self) -> &'static str {
+                        match self { #(#as_str_arms)* }
+                    }
+                }
+            });
+
+            let options = FormValueOptions::from_attrs("form", &data.derive_input.attrs)
+                .unwrap_or_else(|| Ok(Default::default()))?;
+
+            // Values that are integers are matched against the discriminants.
+            let numeric = match options.numeric {
+                true => Some(quote! {
+                    if let #_Ok(__n) = uncased.as_str().parse::<i128>() {
+                        #(#numeric_arms)*
+                    }
+                }),
+                false => None,
+            };
 
-            let variant_str = variant_name_source.name();
+            // Values that match no variant are given to `FromStr`, if requested.
+            let from_str = match options.from_str {
+                true => Some(quote! {
+                    if let #_Ok(v) = <Self as ::std::str::FromStr>::from_str(uncased.as_str()) {
+                        return #_Ok(v);
+                    }
+                }),
+                false => None,
+            };
+
+            // Absent and empty values both yield the default variant, if any.
+            let (empty, default) = match options.default {
+                Some(default) => {
+                    let variant = data.variants()
+                        .find(|variant| variant.ident == default.value)
+                        .ok_or_else(|| default.span.error("unknown variant")
+                            .help("`default` must name a variant of the enum"))?;
+
+                    let builder = variant.builder(|_| unreachable!("no fields"));
+                    let empty = quote!(if uncased.as_str().is_empty() { return #_Ok(#builder); });
+                    let default = quote! {
+                        #[inline]
+                        fn default() -> ::std::option::Option<Self> {
+                            #_Some(#builder)
+                        }
+                    };
+
+                    (Some(empty), Some(default))
+                }
+                None => (None, None),
+            };
 
-            let builder = variant.builder(|_| unreachable!("no fields"));
             Ok(quote! {
-                if uncased == #variant_str {
-                    return #_Ok(#builder);
+                fn from_form_value(
+                    value: &'__v ::rocket::http::RawStr
+                ) -> #_Result<Self, Self::Error> {
+                    let decoded = value.url_decode();
+                    let uncased = match decoded {
+                        #_Ok(ref decoded) => ::rocket::http::uncased::UncasedStr::new(decoded),
+                        #_Err(_) => value.as_uncased_str(),
+                    };
+
+                    #empty
+                    #(#arms)*
+                    #numeric
+                    #from_str
+                    #_Err(::rocket::request::UnknownValue { value, expected: &[#(#values),*] })
                 }
+
+                #default
             })
         })
-        .to_tokens2()
+        .try_to_tokens();
+
+    let from_form_value = match from_form_value {
+        Ok(tokens) => tokens,
+        Err(diag) => return diag.emit_as_item_tokens()
+    };
+
+    let mut tokens = TokenStream::from(from_form_value);
+    tokens.extend(as_str.borrow_mut().take());
+    tokens
 }
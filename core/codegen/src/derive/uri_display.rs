@@ -1,7 +1,7 @@
 
 use devise::{*, ext::SpanDiagnosticExt};
 
-use crate::derive::from_form::Form;
+use crate::derive::from_form::{Form, VariantForm};
 use crate::proc_macro2::TokenStream;
 
 const NO_EMPTY_FIELDS: &str = "fieldless structs or variants are not supported";
@@ -34,6 +34,18 @@ fn validate_enum(_: &DeriveGenerator, data: Enum<'_>) -> Result<()> {
     Ok(())
 }
 
+/// Returns `true` if `input` is a non-empty enum whose variants are all unit
+/// variants. Such enums are rendered as the form value of the variant.
+fn is_unit_enum(input: &proc_macro::TokenStream) -> bool {
+    match syn::parse::<syn::DeriveInput>(input.clone()) {
+        Ok(syn::DeriveInput { data: syn::Data::Enum(data), .. }) => {
+            !data.variants.is_empty()
+                && data.variants.iter().all(|v| matches!(v.fields, syn::Fields::Unit))
+        }
+        _ => false,
+    }
+}
+
 #[allow(non_snake_case)]
 pub fn derive_uri_display_query(input: proc_macro::TokenStream) -> TokenStream {
     let Query = quote!(::rocket::http::uri::Query);
@@ -41,35 +53,57 @@ pub fn derive_uri_display_query(input: proc_macro::TokenStream) -> TokenStream {
     let Formatter = quote!(::rocket::http::uri::Formatter<#Query>);
     let FromUriParam = quote!(::rocket::http::uri::FromUriParam);
 
-    let uri_display = DeriveGenerator::build_for(input.clone(), quote!(impl #UriDisplay))
-        .data_support(DataSupport::Struct | DataSupport::Enum)
-        .generic_support(GenericSupport::Type | GenericSupport::Lifetime)
-        .validate_enum(validate_enum)
-        .validate_struct(|gen, data| validate_fields(&gen.input.ident, data.fields()))
-        .map_type_generic(move |_, ident, _| quote!(#ident : #UriDisplay))
-        .function(move |_, inner| quote! {
-            fn fmt(&self, f: &mut #Formatter) -> ::std::fmt::Result {
-                #inner
-                Ok(())
-            }
-        })
-        .try_map_field(|_, field| {
-            let span = field.span().into();
-            let accessor = field.accessor();
-            let tokens = if let Some(ref ident) = field.ident {
-                let name_source = Form::from_attrs("form", &field.attrs)
-                    .map(|result| result.map(|form| form.field.name))
-                    .unwrap_or_else(|| Ok(ident.clone().into()))?;
-
-                let name = name_source.name();
-                quote_spanned!(span => f.write_named_value(#name, &#accessor)?;)
-            } else {
-                quote_spanned!(span => f.write_value(&#accessor)?;)
-            };
-
-            Ok(tokens)
-        })
-        .try_to_tokens();
+    let uri_display = if is_unit_enum(&input) {
+        DeriveGenerator::build_for(input.clone(), quote!(impl #UriDisplay))
+            .data_support(DataSupport::Enum)
+            .generic_support(GenericSupport::Type | GenericSupport::Lifetime)
+            .map_type_generic(move |_, ident, _| quote!(#ident : #UriDisplay))
+            .function(move |_, inner| quote! {
+                fn fmt(&self, f: &mut #Formatter) -> ::std::fmt::Result {
+                    #inner
+                }
+            })
+            .try_map_enum(|_, data| {
+                // Render the same value that the `FromFormValue` derive matches.
+                let enum_name = &data.derive_input.ident;
+                let arms = data.variants().map(|variant| {
+                    let variant_name = &variant.ident;
+                    let value = VariantForm::value_of(&variant)?;
+                    let value = value.name();
+                    Ok(quote!(#enum_name::#variant_name => f.write_value(#value),))
+                }).collect::<Result<Vec<_>>>()?;
+
+                Ok(quote!(match self { #(#arms)* }))
+            })
+            .try_to_tokens()
+    } else {
+        DeriveGenerator::build_for(input.clone(), quote!(impl #UriDisplay))
+            .data_support(DataSupport::Struct | DataSupport::Enum)
+            .generic_support(GenericSupport::Type | GenericSupport::Lifetime)
+            .validate_enum(validate_enum)
+            .validate_struct(|gen, data| validate_fields(&gen.input.ident, data.fields()))
+            .map_type_generic(move |_, ident, _| quote!(#ident : #UriDisplay))
+            .function(move |_, inner| quote! {
+                fn fmt(&self, f: &mut #Formatter) -> ::std::fmt::Result {
+                    #inner
+                    Ok(())
+                }
+            })
+            .try_map_field(|_, field| {
+                let span = field.span().into();
+                let accessor = field.accessor();
+                let tokens = if field.ident.is_some() {
+                    let name_source = Form::name_of(&field)?;
+                    let name = name_source.name();
+                    quote_spanned!(span => f.write_named_value(#name, &#accessor)?;)
+                } else {
+                    quote_spanned!(span => f.write_value(&#accessor)?;)
+                };
+
+                Ok(tokens)
+            })
+            .try_to_tokens()
+    };
 
     let uri_display = match uri_display {
         Ok(tokens) => tokens,
@@ -211,6 +211,7 @@ macro_rules! route_attribute {
         ///
         /// parameter := 'rank' '=' INTEGER
         ///            | 'format' '=' '"' MEDIA_TYPE '"'
+        ///            | 'assume_format' '=' BOOL
         ///            | 'data' '=' '"' SINGLE_PARAM '"'
         ///
         /// SINGLE_PARAM := '<' IDENT '>'
@@ -220,6 +221,7 @@ macro_rules! route_attribute {
         /// MEDIA_TYPE := valid HTTP media type or known shorthand
         ///
         /// INTEGER := unsigned integer, as defined by Rust
+        /// BOOL := `true` or `false`
         /// IDENT := valid identifier, as defined by Rust, except `_`
         /// ```
         ///
@@ -312,9 +314,9 @@ macro_rules! route_attribute {
         ///   2. A static structure used by [`routes!`] to generate a [`Route`].
         ///
         ///      The static structure (and resulting [`Route`]) is populated
-        ///      with the name (the function's name), path, query, rank, and
-        ///      format from the route attribute. The handler is set to the
-        ///      generated handler.
+        ///      with the name (the function's name), path, query, rank,
+        ///      format, and assumed format from the route attribute. The
+        ///      handler is set to the generated handler.
         ///
         ///   3. A macro used by [`uri!`] to type-check and generate an
         ///      [`Origin`].
@@ -447,11 +449,12 @@ pub fn launch(args: TokenStream, input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// The derive generates an implementation of the [`FromFormValue`] trait for
-/// the decorated `enum`. The implementation returns successfully when the form
-/// value matches, case insensitively, the stringified version of a variant's
-/// name, returning an instance of said variant. If there is no match, an error
-/// ([`FromFormValue::Error`]) of type [`&RawStr`] is returned, the value of
-/// which is the raw form field value that failed to match.
+/// the decorated `enum`. The implementation returns successfully when the
+/// URL-decoded form value matches, case insensitively, the stringified version
+/// of a variant's name, returning an instance of said variant. If there is no match, an error
+/// ([`FromFormValue::Error`]) of type [`UnknownValue`] is returned containing
+/// the raw form field value that failed to match along with the list of valid
+/// values. Its `Display` implementation enumerates the valid values.
 ///
 /// As an example, for the `enum` above, the form values `"first"`, `"FIRST"`,
 /// `"fiRSt"`, and so on would parse as `MyValue::First`, while `"second"` and
@@ -486,9 +489,108 @@ pub fn launch(args: TokenStream, input: TokenStream) -> TokenStream {
 /// variant. In the example above, the the strings `"fourth"`, `"FOUrth"` and so
 /// on would parse as `MyValue::Third`.
 ///
+/// The derive also generates an inherent method, `as_str(&self) -> &'static
+/// str`, that returns the canonical form value of a variant: the variant's
+/// name or, if present, the `value` of its `#[form]` attribute. Handlers can
+/// use it to echo a selection back without matching on the `enum`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromFormValue)]
+/// enum Category {
+///     #[form(value = "CS")]
+///     ComputerScience,
+///     Biology,
+/// }
+///
+/// assert_eq!(Category::ComputerScience.as_str(), "CS");
+/// assert_eq!(Category::Biology.as_str(), "Biology");
+/// ```
+///
+/// As a consequence, the `enum` cannot have another inherent method named
+/// `as_str`.
+///
+/// The derive also accepts a container attribute, `form`, with the following
+/// syntax:
+///
+/// ```text
+/// form := param (',' param)*
+///
+/// param := 'from_str'
+///        | 'numeric'
+///        | 'default' '=' '"' IDENT '"'
+///
+/// IDENT := a variant of the enum
+/// ```
+///
+/// The parameters change how a value is matched. A value is compared, in
+/// order, against:
+///
+///   1. The variant strings, case-insensitively, as described above.
+///   2. With `numeric`, if the value is an integer, the variants'
+///      discriminants.
+///   3. With `from_str`, the `enum`'s [`FromStr`] implementation, which must
+///      exist, given the URL-decoded value.
+///
+/// The first match wins. If nothing matches, the usual [`UnknownValue`] error
+/// is returned. With `default`, which names a variant, an empty value yields
+/// that variant before any of the above is tried, and so does a missing field,
+/// as is common with optional drop-downs. Any other value that matches nothing
+/// remains an error.
+///
+/// With the derive below, `"on"` parses as `Toggle::On` by name, while `"1"`
+/// and `"yes"` parse as `Toggle::On` through `FromStr`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::str::FromStr;
+///
+/// #[derive(FromFormValue)]
+/// #[form(from_str)]
+/// enum Toggle {
+///     On,
+///     Off,
+/// }
+///
+/// impl FromStr for Toggle {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Self, ()> {
+///         match s {
+///             "1" | "yes" => Ok(Toggle::On),
+///             "0" | "no" => Ok(Toggle::Off),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+/// ```
+///
+/// With the derive below, `"high"` and `"10"` parse as `Level::High`, while
+/// `""` and a missing field yield `Level::Medium`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromFormValue)]
+/// #[form(numeric, default = "Medium")]
+/// enum Level {
+///     Low,
+///     Medium,
+///     High = 10,
+/// }
+/// ```
+///
+/// Deriving [`UriDisplay<Query>`] for the same `enum` renders each variant as
+/// the string it is matched against here, so values passed to `uri!` parse
+/// back to the same variant.
+///
 /// [`FromFormValue`]: ../rocket/request/trait.FromFormValue.html
+/// [`UriDisplay<Query>`]: ../rocket/http/uri/trait.UriDisplay.html
 /// [`FromFormValue::Error`]: ../rocket/request/trait.FromFormValue.html#associatedtype.Error
-/// [`&RawStr`]: ../rocket/http/struct.RawStr.html
+/// [`UnknownValue`]: ../rocket/request/struct.UnknownValue.html
+/// [`FromStr`]: std::str::FromStr
 // FIXME(rustdoc): We should be able to refer to items in `rocket`.
 #[proc_macro_derive(FromFormValue, attributes(form))]
 pub fn derive_from_form_value(input: TokenStream) -> TokenStream {
@@ -522,9 +624,23 @@ pub fn derive_from_form_value(input: TokenStream) -> TokenStream {
 /// The derive accepts one field attribute: `form`, with the following syntax:
 ///
 /// ```text
-/// form := 'field' '=' '"' IDENT '"'
+/// form := param (',' param)*
+///
+/// param := 'field' '=' '"' IDENT '"'
+///        | 'transform' '=' '"' PATH '"'
+///        | 'parse_with' '=' '"' EXPR '"'
+///        | 'decimal' '=' '"' (',' | '.') '"'
+///        | 'default_if' '=' '"' STRING '"'
+///        | 'format' '=' '"' STRING '"'
+///        | 'trim'
+///        | 'default' '=' '"' EXPR '"'
+///        | 'default_with' '=' '"' PATH '"'
+///        | 'compute' '=' '"' EXPR '"'
 ///
 /// IDENT := valid identifier, as defined by Rust
+/// PATH := path to a function, as defined by Rust
+/// STRING := any string
+/// EXPR := expression, as defined by Rust
 /// ```
 ///
 /// When applied, the attribute looks as follows:
@@ -546,9 +662,385 @@ pub fn derive_from_form_value(input: TokenStream) -> TokenStream {
 /// value of the `MyStruct::other` struct field will be parsed from the incoming
 /// form's `renamed_field` field.
 ///
+/// The `transform` parameter names a function of type `fn(&str) -> Cow<str>`
+/// that is applied to the URL-decoded value of the field before the value is
+/// parsed. The field's [`FromFormValue`] implementation sees the transformed
+/// value. Transforms are useful for normalizing input:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::borrow::Cow;
+///
+/// fn lowercase(value: &str) -> Cow<'_, str> {
+///     value.to_lowercase().into()
+/// }
+///
+/// #[derive(FromForm)]
+/// struct Signup {
+///     #[form(transform = "lowercase")]
+///     email: String,
+/// }
+/// ```
+///
+/// Because the transformed value does not live as long as the form, the
+/// field's type must not borrow from the form when `transform` is used.
+///
+/// The `trim` parameter removes leading and trailing whitespace from the
+/// URL-decoded value of the field before it is transformed, parsed, or
+/// otherwise inspected, so that a value of only spaces is treated as an empty
+/// value. Like `transform`, it requires that the field's type not borrow from
+/// the form; using either with a borrowed type like `&RawStr` is a
+/// compile-time error:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// struct Signup {
+///     #[form(trim)]
+///     name: String,
+/// }
+/// ```
+///
+/// The `parse_with` parameter names a function of type `fn(&str) -> Result<T,
+/// E>`, where `T` is the field's type, that is used to parse the URL-decoded
+/// (and transformed, if `transform` is also present) value of the field in
+/// place of [`FromFormValue`]. The field's type need not implement
+/// `FromFormValue`. If the function returns an error, parsing fails with a
+/// [`FormParseError::BadValue`]. Because there is no `FromFormValue`
+/// implementation to provide a default, a field parsed with `parse_with` is
+/// required unless `default` or `default_with` is also present:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::time::Duration;
+/// use std::num::ParseIntError;
+///
+/// fn seconds(value: &str) -> Result<Duration, ParseIntError> {
+///     value.parse().map(Duration::from_secs)
+/// }
+///
+/// #[derive(FromForm)]
+/// struct Timer {
+///     #[form(parse_with = "seconds")]
+///     timeout: Duration,
+/// }
+/// ```
+///
+/// The value of `parse_with` may also be an expression that evaluates to such a
+/// function. For instance, [`in_subnet()`] returns a parser that only accepts
+/// IP addresses in a given subnet:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::net::IpAddr;
+/// use rocket::request::in_subnet;
+///
+/// #[derive(FromForm)]
+/// struct Node {
+///     #[form(parse_with = r#"in_subnet("10.0.0.0/8")"#)]
+///     addr: IpAddr,
+/// }
+/// ```
+///
+/// Likewise, [`in_range()`] returns a parser that only accepts numbers within
+/// a Rust range, inclusive or exclusive:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use rocket::request::in_range;
+///
+/// #[derive(FromForm)]
+/// struct Survey {
+///     #[form(parse_with = "in_range(1..=10)")]
+///     rating: u8,
+/// }
+/// ```
+///
+/// The expression is evaluated once, the first time a form of the type is
+/// parsed, and the resulting function, which must be `Send + Sync`, is used
+/// for every value of the field from then on. Because the function is kept in a
+/// `static`, the field's type can't have lifetimes or generic parameters.
+///
+/// When Rocket's `regex` feature is enabled and the expression calls
+/// `rocket::request::matching()`, spelled with that full path, with a string
+/// literal, the literal is checked to be a valid regular expression at
+/// compile-time.
+///
+/// The `decimal` parameter sets the decimal separator of a numeric field. By
+/// default, numbers are parsed with a decimal point, as in `12.50`. With
+/// `decimal = ","`, the field's value must instead use a decimal comma, as in
+/// `12,50`, which is common in European locales. Values containing a point are
+/// then rejected with a [`FormParseError::BadValue`] to avoid ambiguity with
+/// thousands separators. The separator is fixed per field; it is never
+/// inferred from the request:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// struct Order {
+///     #[form(decimal = ",")]
+///     amount: f64,
+/// }
+/// ```
+///
+/// `decimal` cannot be combined with `transform`, `parse_with`, or `format`.
+///
+/// The `default_if` parameter sets a sentinel value that, when submitted,
+/// resets the field to its default value, as given by
+/// [`FromFormValue::default()`], instead of parsing it. The sentinel is
+/// compared to the URL-decoded, and trimmed if `trim` is present, value before
+/// it is transformed. This differs
+/// from a missing field only in that the field _is_ present: an empty
+/// `<select>` option, for instance, can select a default enum variant while
+/// every other value is parsed as usual. If the field's type has no default,
+/// submitting the sentinel is the same as omitting the field:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromFormValue)]
+/// enum Shipping { Standard, Express }
+///
+/// #[derive(FromForm)]
+/// struct Order {
+///     #[form(default_if = "")]
+///     shipping: Option<Shipping>,
+/// }
+/// ```
+///
+/// `default_if` cannot be combined with `parse_with` unless `default` or
+/// `default_with` is also present, in which case the sentinel resets the field
+/// to that default.
+///
+/// The `format` parameter sets the format string of a date or time field. The
+/// URL-decoded (and transformed, if `transform` is also present) value of the
+/// field is parsed with [`FromFormattedValue`] in place of [`FromFormValue`],
+/// which accepts only ISO 8601 values. This allows forms to submit dates as,
+/// for instance, `03/01/2021`. The field's type must implement
+/// `FromFormattedValue`, as `time`'s `Date`, `Time`, `PrimitiveDateTime`, and
+/// `OffsetDateTime` and `Option`s of them do; using `format` on any other
+/// field is a compile-time error. If the value doesn't match the format,
+/// parsing fails with a [`FormParseError::BadValue`]:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use time::{Date, Time};
+///
+/// #[derive(FromForm)]
+/// struct Booking {
+///     #[form(format = "%m/%d/%Y")]
+///     arrival: Date,
+///     #[form(format = "%I:%M %p")]
+///     check_in: Option<Time>,
+/// }
+/// ```
+///
+/// `format` cannot be combined with `parse_with`.
+///
+/// The `default` parameter sets the value of a field that is missing from the
+/// form. Without it, a missing field is set to its type's default, as given
+/// by [`FromFormValue::default()`], and if there is none, parsing fails with a
+/// [`FormParseError::Missing`]. The expression is evaluated only when the
+/// field is missing and must evaluate to a value of the field's type. A field
+/// that is present but invalid is still an error, and in strict mode unknown
+/// fields are still rejected:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// struct Search {
+///     query: String,
+///     #[form(default = "20")]
+///     limit: usize,
+///     #[form(default = r#""relevance".to_string()"#)]
+///     order: String,
+/// }
+/// ```
+///
+/// The `default_with` parameter instead names a function of type `fn() -> T`,
+/// where `T` is the field's type, that is called to produce the value of a
+/// missing field. This is useful for defaults that aren't constant, such as
+/// the current time. At most one of `default` and `default_with` may be
+/// present:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::time::SystemTime;
+///
+/// #[derive(FromForm)]
+/// struct Event {
+///     name: String,
+///     #[form(parse_with = "parse_timestamp", default_with = "SystemTime::now")]
+///     at: SystemTime,
+/// }
+/// # fn parse_timestamp(_: &str) -> Result<SystemTime, ()> { Err(()) }
+/// ```
+///
+/// The `compute` parameter marks a field that is not parsed from the form but
+/// computed from its sibling fields once they have been parsed. The expression
+/// can refer to every other field by name; each is bound to a reference to the
+/// field's parsed value. Computed fields never read from the form: a submitted
+/// field with the same name is treated like any other unknown field, so it is
+/// ignored when parsing leniently and rejected when parsing strictly. Computed
+/// fields cannot refer to one another, and `compute` cannot be combined with
+/// any other parameter:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// struct Person {
+///     first: String,
+///     last: String,
+///     #[form(compute = r#"format!("{} {}", first, last)"#)]
+///     full_name: String,
+/// }
+/// ```
+///
+/// Fields of type `PhantomData` are not parsed from the form. They are always
+/// initialized to their default value and need not be annotated:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::marker::PhantomData;
+///
+/// struct Unverified;
+///
+/// #[derive(FromForm)]
+/// struct Signup {
+///     email: String,
+///     state: PhantomData<Unverified>,
+/// }
+/// ```
+///
+/// The derive also accepts a container attribute, `form`, with the following
+/// syntax:
+///
+/// ```text
+/// form := param (',' param)*
+///
+/// param := 'capture_unknown' '=' '"' IDENT '"'
+///        | 'allowed' '=' '"' NAME (',' NAME)* '"'
+///        | 'require' '=' '"' NAME (',' NAME)* '"'
+///        | 'tag' '=' '"' NAME '"'
+///
+/// IDENT := valid identifier, as defined by Rust
+/// NAME := valid form field name
+/// ```
+///
+/// `capture_unknown` and `require` are only supported on structures, and `tag`
+/// only on enums, as described in [Tagged Enums](#tagged-enums).
+///
+/// The `capture_unknown` parameter names a field of the structure. When
+/// present, any form key that doesn't match another field of the structure is
+/// URL-decoded along with its value and added to the named field instead of
+/// being rejected (strict parsing) or ignored (lenient parsing). The field's
+/// type must implement `Default` and `Extend<(String, String)>`, as
+/// `HashMap<String, String>` does:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// use std::collections::HashMap;
+///
+/// #[derive(FromForm)]
+/// #[form(capture_unknown = "extra")]
+/// struct Profile {
+///     name: String,
+///     extra: HashMap<String, String>,
+/// }
+/// ```
+///
+/// Sensitive forms can instead enumerate the exact set of keys they accept
+/// with the `allowed` parameter, a comma-separated list of field names. Any
+/// key outside of the list, other than `_method`, fails parsing with
+/// [`FormParseError::Unknown`], regardless of whether it names a field of the
+/// structure and even when parsing leniently. Listed keys that don't name a
+/// field are accepted and ignored, even when parsing strictly:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// #[form(allowed = "email, password, csrf_token")]
+/// struct Login {
+///     email: String,
+///     password: String,
+/// }
+/// ```
+///
+/// The `require` parameter, a comma-separated list of field names, declares
+/// fields that must be present in the form. When any listed field is missing,
+/// parsing fails with a single [`FormParseError::MissingRequired`] error that
+/// names every missing field, rather than with a [`FormParseError::Missing`]
+/// error for the first one. A listed field is required even if its type
+/// provides a default, as `Option` does. Each name must be the form name of a
+/// field of the structure, and at most 64 fields can be listed:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// #[form(require = "name, email")]
+/// struct Contact {
+///     name: String,
+///     email: String,
+///     phone: Option<String>,
+/// }
+/// ```
+///
+/// # Tagged Enums
+///
+/// The derive can also be applied to enums whose variants have named fields or
+/// no fields at all. The enum must declare the name of a _tag_ field with the
+/// `tag` container parameter. The value of the tag field selects the variant to
+/// parse; the remaining fields are parsed as the variant's fields, exactly as
+/// they would be for a structure. Tag values are URL-decoded and then matched
+/// against variant names case-insensitively. Variants can be renamed with a
+/// `#[form(value = "...")]` variant attribute, as with the [`FromFormValue`]
+/// derive:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #
+/// #[derive(FromForm)]
+/// #[form(tag = "kind")]
+/// enum Payment {
+///     Card { number: String, cvv: u16 },
+///     #[form(value = "bank_transfer")]
+///     Transfer { iban: String },
+///     Cash,
+/// }
+/// ```
+///
+/// With the derive above, the form `kind=card&number=4242&cvv=123` parses as
+/// `Payment::Card`. If the tag field is missing, parsing fails with
+/// [`FormParseError::Missing`]. If its value doesn't match any variant, parsing
+/// fails with [`FormParseError::UnknownTag`], which contains the list of valid
+/// values.
+///
 /// [`FromForm`]: ../rocket/request/trait.FromForm.html
 /// [`FromFormValue`]: ../rocket/request/trait.FromFormValue.html
+/// [`FromFormattedValue`]: ../rocket/request/trait.FromFormattedValue.html
+/// [`FromFormValue::default()`]: ../rocket/request/trait.FromFormValue.html#method.default
+/// [`in_subnet()`]: ../rocket/request/fn.in_subnet.html
+/// [`in_range()`]: ../rocket/request/fn.in_range.html
 /// [`FormParseError`]: ../rocket/request/enum.FormParseError.html
+/// [`FormParseError::BadValue`]: ../rocket/request/enum.FormParseError.html#variant.BadValue
+/// [`FormParseError::Missing`]: ../rocket/request/enum.FormParseError.html#variant.Missing
+/// [`FormParseError::MissingRequired`]: ../rocket/request/enum.FormParseError.html#variant.MissingRequired
+/// [`FormParseError::Unknown`]: ../rocket/request/enum.FormParseError.html#variant.Unknown
+/// [`FormParseError::UnknownTag`]: ../rocket/request/enum.FormParseError.html#variant.UnknownTag
 /// [`FromForm::Error`]: ../rocket/request/trait.FromForm.html#associatedtype.Error
 #[proc_macro_derive(FromForm, attributes(form))]
 pub fn derive_from_form(input: TokenStream) -> TokenStream {
@@ -689,8 +1181,9 @@ pub fn derive_responder(input: TokenStream) -> TokenStream {
 /// Derive for the [`UriDisplay<Query>`] trait.
 ///
 /// The [`UriDisplay<Query>`] derive can be applied to enums and structs. When
-/// applied to enums, variants must have at least one field. When applied to
-/// structs, the struct must have at least one field.
+/// applied to enums, either every variant must have at least one field or
+/// every variant must be a unit variant. When applied to structs, the struct
+/// must have at least one field.
 ///
 /// ```rust
 /// # #[macro_use] extern crate rocket;
@@ -745,6 +1238,30 @@ pub fn derive_responder(input: TokenStream) -> TokenStream {
 /// the example above, the field `MyStruct::kind` is rendered with a name of
 /// `type`.
 ///
+/// # Unit Enums
+///
+/// An enum whose variants are all unit variants is rendered as the value of the
+/// variant: the variant's name or, if set, the value of its `#[form(value =
+/// "...")]` attribute. This is the value that the [`FromFormValue`] derive
+/// matches, so the two derives can be used together to round-trip values
+/// through `uri!`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(FromFormValue, UriDisplayQuery)]
+/// enum Sort {
+///     Newest,
+///     #[form(value = "top-rated")]
+///     TopRated,
+/// }
+///
+/// #[get("/items?<sort>")]
+/// fn items(sort: Sort) { /* .. */ }
+///
+/// assert_eq!(uri!(items: Sort::TopRated).to_string(), "/items?sort=top-rated");
+/// ```
+///
+/// [`FromFormValue`]: ../rocket/request/trait.FromFormValue.html
 /// [`UriDisplay<Query>`]: ../rocket/http/uri/trait.UriDisplay.html
 /// [`Formatter::write_named_value()`]: ../rocket/http/uri/struct.Formatter.html#method.write_named_value
 /// [`Formatter::write_value()`]: ../rocket/http/uri/struct.Formatter.html#method.write_value
@@ -1,6 +1,6 @@
 #[macro_use] extern crate rocket;
 
-use rocket::request::{FromForm, FormItems, FormParseError};
+use rocket::request::{FromForm, FormItems, FormParseError, UnknownValue};
 use rocket::http::RawStr;
 
 fn parse<'f, T>(string: &'f str, strict: bool) -> Result<T, FormParseError<'f>>
@@ -328,3 +328,703 @@ fn raw_ident_form() {
     let form: Result<RawIdentForm, _> = strict("type=a");
     assert_eq!(form, Ok(RawIdentForm { r#type: "a".into() }));
 }
+
+#[derive(Debug, PartialEq, FromForm)]
+struct ResultForm<'r> {
+    name: String,
+    age: Result<u16, &'r RawStr>,
+    radio: Result<FormOption, UnknownValue<'r>>,
+}
+
+#[test]
+fn result_fields_retain_errors() {
+    let form: Result<ResultForm<'_>, _> = strict("name=bob&age=abc&radio=b");
+    assert_eq!(form, Ok(ResultForm {
+        name: "bob".into(),
+        age: Err("abc".into()),
+        radio: Ok(FormOption::B),
+    }));
+
+    let form: Result<ResultForm<'_>, _> = strict("name=bob&age=25&radio=d");
+    assert_eq!(form, Ok(ResultForm {
+        name: "bob".into(),
+        age: Ok(25),
+        radio: Err(UnknownValue { value: "d".into(), expected: &["A", "B", "C"] }),
+    }));
+
+    // A `Result` field catches bad values but is still required.
+    let form: Result<ResultForm<'_>, _> = strict("name=bob&radio=a");
+    assert_eq!(form, Err(FormParseError::Missing("age".into())));
+
+    // Sibling fields continue to report their own errors.
+    let form: Result<ResultForm<'_>, _> = strict("name=%FF&age=10&radio=a");
+    assert_eq!(form, Err(FormParseError::BadValue("name".into(), "%FF".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct SignedForm<'r> {
+    payload: std::borrow::Cow<'r, [u8]>,
+    signature: String,
+}
+
+#[test]
+fn raw_byte_fields() {
+    let form: Result<SignedForm<'_>, _> = strict("payload=a+b%20c%26d&signature=abc%3D");
+    assert_eq!(form, Ok(SignedForm {
+        payload: b"a+b%20c%26d"[..].into(),
+        signature: "abc=".into(),
+    }));
+
+    let form: Result<SignedForm<'_>, _> = strict("payload=&signature=x");
+    assert_eq!(form.unwrap().payload, &b""[..]);
+}
+
+fn lowercase(value: &str) -> std::borrow::Cow<'_, str> {
+    value.to_lowercase().into()
+}
+
+fn strip_underscores(value: &str) -> std::borrow::Cow<'_, str> {
+    value.replace('_', "").into()
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct TransformedForm {
+    #[form(transform = "lowercase")]
+    email: String,
+    #[form(field = "n", transform = "strip_underscores")]
+    number: usize,
+}
+
+#[test]
+fn field_transforms() {
+    let form: Result<TransformedForm, _> = strict("email=Bob%40Example.COM&n=1_000");
+    assert_eq!(form, Ok(TransformedForm {
+        email: "bob@example.com".into(),
+        number: 1000,
+    }));
+
+    // The transformed value, not the submitted one, is parsed.
+    let form: Result<TransformedForm, _> = strict("email=A+B%2BC&n=12");
+    assert_eq!(form, Ok(TransformedForm {
+        email: "a b+c".into(),
+        number: 12,
+    }));
+
+    // Errors refer to the submitted value.
+    let form: Result<TransformedForm, _> = strict("email=a&n=1_x");
+    assert_eq!(form, Err(FormParseError::BadValue("n".into(), "1_x".into())));
+}
+
+#[derive(Debug, PartialEq)]
+struct Rgb(u8, u8, u8);
+
+fn hex_color(value: &str) -> Result<Rgb, std::num::ParseIntError> {
+    let value = value.trim_start_matches('#');
+    let channel = |i: usize| u8::from_str_radix(value.get(i..i + 2).unwrap_or("x"), 16);
+    Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct ParsedForm {
+    #[form(parse_with = "hex_color")]
+    color: Rgb,
+    #[form(field = "bg", transform = "lowercase", parse_with = "hex_color")]
+    background: Rgb,
+    name: String,
+}
+
+#[test]
+fn field_parse_with() {
+    let form: Result<ParsedForm, _> = strict("color=%23ff8000&bg=00FF00&name=Bob");
+    assert_eq!(form, Ok(ParsedForm {
+        color: Rgb(255, 128, 0),
+        background: Rgb(0, 255, 0),
+        name: "Bob".into(),
+    }));
+
+    let form: Result<ParsedForm, _> = strict("color=red&bg=00ff00&name=Bob");
+    assert_eq!(form, Err(FormParseError::BadValue("color".into(), "red".into())));
+
+    let form: Result<ParsedForm, _> = strict("bg=00ff00&name=Bob");
+    assert_eq!(form, Err(FormParseError::Missing("color".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct NodeForm {
+    #[form(parse_with = r#"rocket::request::in_subnet("10.0.0.0/8")"#)]
+    addr: std::net::IpAddr,
+    #[form(parse_with = r#"rocket::request::in_subnet("fd00::/8")"#)]
+    addr6: std::net::IpAddr,
+}
+
+#[test]
+fn field_in_subnet() {
+    let form: Result<NodeForm, _> = strict("addr=10.0.0.1&addr6=fd12%3A%3A1");
+    assert_eq!(form, Ok(NodeForm {
+        addr: "10.0.0.1".parse().unwrap(),
+        addr6: "fd12::1".parse().unwrap(),
+    }));
+
+    let form: Result<NodeForm, _> = strict("addr=10.255.255.255&addr6=");
+    assert_eq!(form, Err(FormParseError::BadValue("addr6".into(), "".into())));
+
+    let form: Result<NodeForm, _> = strict("addr=11.0.0.1&addr6=fd00::1");
+    assert_eq!(form, Err(FormParseError::BadValue("addr".into(), "11.0.0.1".into())));
+
+    let form: Result<NodeForm, _> = strict("addr=10.0.0.1&addr6=fe80::1");
+    assert_eq!(form, Err(FormParseError::BadValue("addr6".into(), "fe80::1".into())));
+
+    // Addresses of the other family are never in the subnet.
+    let form: Result<NodeForm, _> = strict("addr=::ffff:10.0.0.1&addr6=fd00::1");
+    assert_eq!(form, Err(FormParseError::BadValue("addr".into(), "::ffff:10.0.0.1".into())));
+
+    let error = rocket::request::in_subnet("10.0.0.0/8")("11.0.0.1").unwrap_err();
+    assert_eq!(error.to_string(), "11.0.0.1 is not in 10.0.0.0/8");
+}
+
+#[test]
+#[should_panic(expected = "invalid subnet \"10.0.0.0/33\"")]
+fn invalid_subnet_panics_on_construction() {
+    // The subnet is parsed, and rejected, before any value is parsed.
+    let _ = rocket::request::in_subnet("10.0.0.0/33");
+}
+
+static PARSERS_BUILT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn counted() -> impl Fn(&str) -> Result<u16, std::num::ParseIntError> {
+    PARSERS_BUILT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    |value| value.parse()
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct CountedForm {
+    #[form(parse_with = "counted()")]
+    port: u16,
+}
+
+#[test]
+fn parse_with_parsers_are_built_once() {
+    for port in &[80, 443, 8080] {
+        let string = format!("port={}", port);
+        let form: Result<CountedForm, _> = strict(&string);
+        assert_eq!(form, Ok(CountedForm { port: *port }));
+    }
+
+    let form: Result<CountedForm, _> = strict("port=http");
+    assert!(form.is_err());
+    assert_eq!(PARSERS_BUILT.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct SurveyForm {
+    #[form(parse_with = "rocket::request::in_range(1..=10)")]
+    rating: u8,
+    #[form(parse_with = "rocket::request::in_range(0.0..1.0)")]
+    ratio: f64,
+    #[form(parse_with = "rocket::request::in_range(18..)")]
+    age: u16,
+}
+
+#[test]
+fn field_in_range() {
+    let form: Result<SurveyForm, _> = strict("rating=10&ratio=0.5&age=18");
+    assert_eq!(form, Ok(SurveyForm { rating: 10, ratio: 0.5, age: 18 }));
+
+    let form: Result<SurveyForm, _> = strict("rating=1&ratio=0&age=65535");
+    assert_eq!(form, Ok(SurveyForm { rating: 1, ratio: 0.0, age: 65535 }));
+
+    let form: Result<SurveyForm, _> = strict("rating=0&ratio=0.5&age=18");
+    assert_eq!(form, Err(FormParseError::BadValue("rating".into(), "0".into())));
+
+    let form: Result<SurveyForm, _> = strict("rating=11&ratio=0.5&age=18");
+    assert_eq!(form, Err(FormParseError::BadValue("rating".into(), "11".into())));
+
+    // The end of an exclusive range, and `NaN`, are out of range.
+    let form: Result<SurveyForm, _> = strict("rating=5&ratio=1.0&age=18");
+    assert_eq!(form, Err(FormParseError::BadValue("ratio".into(), "1.0".into())));
+
+    let form: Result<SurveyForm, _> = strict("rating=5&ratio=NaN&age=18");
+    assert_eq!(form, Err(FormParseError::BadValue("ratio".into(), "NaN".into())));
+
+    let form: Result<SurveyForm, _> = strict("rating=5&ratio=0.5&age=17");
+    assert_eq!(form, Err(FormParseError::BadValue("age".into(), "17".into())));
+
+    let form: Result<SurveyForm, _> = strict("rating=five&ratio=0.5&age=18");
+    assert_eq!(form, Err(FormParseError::BadValue("rating".into(), "five".into())));
+
+    let error = rocket::request::in_range(1..=10)("11").unwrap_err();
+    assert_eq!(error.to_string(), "11 is out of range: must be at least 1 and at most 10");
+
+    let error = rocket::request::in_range(..0)("0").unwrap_err();
+    assert_eq!(error.to_string(), "0 is out of range: must be less than 0");
+
+    let error = rocket::request::in_range::<u8, _>(18..)("300").unwrap_err();
+    assert!(error.to_string().starts_with("invalid value: "));
+}
+
+const COLORS: &[&str] = &["red", "green", "blue"];
+
+#[derive(Debug, PartialEq, FromForm)]
+struct PaintForm {
+    #[form(parse_with = "rocket::request::one_of(COLORS)")]
+    color: String,
+    #[form(parse_with = r#"rocket::request::one_of(&["matte", "gloss"])"#)]
+    finish: String,
+}
+
+#[test]
+fn field_one_of() {
+    let form: Result<PaintForm, _> = strict("color=green&finish=gloss");
+    assert_eq!(form, Ok(PaintForm { color: "green".into(), finish: "gloss".into() }));
+
+    let form: Result<PaintForm, _> = strict("color=%62lue&finish=matte");
+    assert_eq!(form, Ok(PaintForm { color: "blue".into(), finish: "matte".into() }));
+
+    let form: Result<PaintForm, _> = strict("color=Red&finish=matte");
+    assert_eq!(form, Err(FormParseError::BadValue("color".into(), "Red".into())));
+
+    let form: Result<PaintForm, _> = strict("color=red&finish=");
+    assert_eq!(form, Err(FormParseError::BadValue("finish".into(), "".into())));
+
+    let error = rocket::request::one_of(COLORS)("purple").unwrap_err();
+    assert_eq!(error, UnknownValue { value: "purple".into(), expected: COLORS });
+    assert_eq!(error.to_string(), "unknown value \"purple\"; expected one of: red, green, blue");
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+#[form(capture_unknown = "extra")]
+struct CaptureForm {
+    name: String,
+    #[form(field = "a")]
+    age: usize,
+    extra: std::collections::HashMap<String, String>,
+}
+
+#[test]
+fn capture_unknown_fields() {
+    let form: Result<CaptureForm, _> = strict("name=Bob&theme=dark+mode&a=30&x%20y=1");
+    let form = form.unwrap();
+    assert_eq!(form.name, "Bob");
+    assert_eq!(form.age, 30);
+    assert_eq!(form.extra.len(), 2);
+    assert_eq!(form.extra["theme"], "dark mode");
+    assert_eq!(form.extra["x y"], "1");
+
+    // Known fields are still parsed normally and are never captured.
+    let form: Result<CaptureForm, _> = lenient("name=Bob&a=30&_method=patch");
+    assert_eq!(form, Ok(CaptureForm { name: "Bob".into(), age: 30, extra: Default::default() }));
+
+    let form: Result<CaptureForm, _> = strict("name=Bob&a=x&theme=dark");
+    assert_eq!(form, Err(FormParseError::BadValue("a".into(), "x".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Price {
+    #[form(decimal = ",")]
+    amount: f64,
+    #[form(decimal = ".")]
+    total: f64,
+    #[form(decimal = ",")]
+    tip: Option<f32>,
+}
+
+#[test]
+fn decimal_separators() {
+    let form: Result<Price, _> = strict("amount=12,50&total=12.50");
+    assert_eq!(form, Ok(Price { amount: 12.5, total: 12.5, tip: None }));
+
+    let form: Result<Price, _> = strict("amount=12%2C50&total=3&tip=1,5");
+    assert_eq!(form, Ok(Price { amount: 12.5, total: 3.0, tip: Some(1.5) }));
+
+    let form: Result<Price, _> = strict("amount=12.50&total=12.50");
+    assert_eq!(form, Err(FormParseError::BadValue("amount".into(), "12.50".into())));
+
+    let form: Result<Price, _> = strict("amount=1.250,50&total=12.50");
+    assert_eq!(form, Err(FormParseError::BadValue("amount".into(), "1.250,50".into())));
+
+    let form: Result<Price, _> = strict("amount=12,50&total=12,50");
+    assert_eq!(form, Err(FormParseError::BadValue("total".into(), "12,50".into())));
+}
+
+#[derive(Debug, PartialEq, FromFormValue)]
+enum Shipping {
+    Standard,
+    Express,
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Order {
+    #[form(default_if = "")]
+    shipping: Option<Shipping>,
+    #[form(default_if = "none")]
+    gift: bool,
+    #[form(default_if = "-")]
+    quantity: usize,
+}
+
+#[test]
+fn default_if_sentinels() {
+    let form: Result<Order, _> = strict("shipping=express&gift=true&quantity=2");
+    assert_eq!(form, Ok(Order { shipping: Some(Shipping::Express), gift: true, quantity: 2 }));
+
+    // The sentinel yields the default, even when a value was already parsed.
+    let form: Result<Order, _> = strict("shipping=&gift=true&gift=none&quantity=2");
+    assert_eq!(form, Ok(Order { shipping: None, gift: false, quantity: 2 }));
+
+    // Sentinels are compared to the whole, decoded value.
+    let form: Result<Order, _> = strict("shipping=standard&gift=n%6Fne&quantity=1");
+    assert_eq!(form, Ok(Order { shipping: Some(Shipping::Standard), gift: false, quantity: 1 }));
+
+    let form: Result<Order, _> = strict("gift=nonex&quantity=1");
+    assert_eq!(form, Err(FormParseError::BadValue("gift".into(), "nonex".into())));
+
+    // Without a default, the sentinel is the same as a missing field.
+    let form: Result<Order, _> = strict("shipping=standard&quantity=-");
+    assert_eq!(form, Err(FormParseError::Missing("quantity".into())));
+}
+
+#[derive(Debug, PartialEq)]
+struct Unverified;
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Signup {
+    email: String,
+    state: std::marker::PhantomData<Unverified>,
+}
+
+#[test]
+fn phantom_data_fields() {
+    let form: Result<Signup, _> = strict("email=a@b");
+    assert_eq!(form, Ok(Signup { email: "a@b".into(), state: std::marker::PhantomData }));
+
+    // `PhantomData` fields aren't form fields.
+    let form: Result<Signup, _> = strict("email=a@b&state=verified");
+    assert_eq!(form, Err(FormParseError::Unknown("state".into(), "verified".into())));
+
+    let form: Result<Signup, _> = lenient("email=a@b&state=verified");
+    assert_eq!(form, Ok(Signup { email: "a@b".into(), state: std::marker::PhantomData }));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Account<S, T> {
+    email: String,
+    role: T,
+    state: std::marker::PhantomData<S>,
+}
+
+#[test]
+fn generic_phantom_data_fields() {
+    // `Unverified` doesn't implement `FromFormValue`; it needn't, as `S` is
+    // only used in a `PhantomData`.
+    let form: Result<Account<Unverified, u8>, _> = strict("email=a@b&role=3");
+    let state = std::marker::PhantomData;
+    assert_eq!(form, Ok(Account { email: "a@b".into(), role: 3, state }));
+
+    let form: Result<Account<Unverified, u8>, _> = strict("email=a@b&role=admin");
+    assert_eq!(form, Err(FormParseError::BadValue("role".into(), "admin".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+#[form(allowed = "email, password, csrf_token")]
+struct LoginForm {
+    email: String,
+    password: String,
+    remember: Option<bool>,
+}
+
+#[test]
+fn allowed_field_names() {
+    let form: Result<LoginForm, _> = strict("email=a@b&password=hunter2&csrf_token=abc");
+    assert_eq!(form, Ok(LoginForm {
+        email: "a@b".into(), password: "hunter2".into(), remember: None
+    }));
+
+    // Keys outside of the list are rejected, even in lenient mode.
+    let form: Result<LoginForm, _> = lenient("email=a@b&password=hunter2&admin=true");
+    assert_eq!(form, Err(FormParseError::Unknown("admin".into(), "true".into())));
+
+    // ...even when they name a field of the structure.
+    let form: Result<LoginForm, _> = lenient("email=a@b&password=hunter2&remember=true");
+    assert_eq!(form, Err(FormParseError::Unknown("remember".into(), "true".into())));
+
+    let form: Result<LoginForm, _> = strict("email=a@b&password=hunter2&_method=put");
+    assert!(form.is_ok());
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+#[form(tag = "kind")]
+enum Payment {
+    Card { number: String, cvv: u16 },
+    #[form(value = "bank_transfer")]
+    Transfer {
+        #[form(field = "account")]
+        iban: String,
+    },
+    Cash,
+}
+
+#[test]
+fn tagged_enums() {
+    let form: Result<Payment, _> = strict("kind=card&number=4242+4242&cvv=123");
+    assert_eq!(form, Ok(Payment::Card { number: "4242 4242".into(), cvv: 123 }));
+
+    let form: Result<Payment, _> = strict("number=4242&kind=CARD&cvv=123");
+    assert_eq!(form, Ok(Payment::Card { number: "4242".into(), cvv: 123 }));
+
+    let form: Result<Payment, _> = strict("kind=bank_transfer&account=DE89");
+    assert_eq!(form, Ok(Payment::Transfer { iban: "DE89".into() }));
+
+    let form: Result<Payment, _> = strict("kind=cash");
+    assert_eq!(form, Ok(Payment::Cash));
+
+    // The tag is URL-decoded before it is matched.
+    let form: Result<Payment, _> = strict("kind=%63ash");
+    assert_eq!(form, Ok(Payment::Cash));
+
+    let form: Result<Payment, _> = strict("kind=bank%5Ftransfer&account=DE89");
+    assert_eq!(form, Ok(Payment::Transfer { iban: "DE89".into() }));
+
+    // Fields are parsed according to the selected variant.
+    let form: Result<Payment, _> = strict("kind=cash&number=4242");
+    assert_eq!(form, Err(FormParseError::Unknown("number".into(), "4242".into())));
+
+    let form: Result<Payment, _> = lenient("kind=cash&number=4242");
+    assert_eq!(form, Ok(Payment::Cash));
+
+    let form: Result<Payment, _> = strict("kind=card&number=4242");
+    assert_eq!(form, Err(FormParseError::Missing("cvv".into())));
+
+    let form: Result<Payment, _> = strict("number=4242&cvv=123");
+    assert_eq!(form, Err(FormParseError::Missing("kind".into())));
+
+    let form: Result<Payment, _> = strict("kind=cheque");
+    assert_eq!(form, Err(FormParseError::UnknownTag(
+        "kind".into(), "cheque".into(), &["Card", "bank_transfer", "Cash"]
+    )));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Person {
+    first: String,
+    #[form(field = "family")]
+    last: String,
+    #[form(compute = r#"format!("{} {}", first, last)"#)]
+    full_name: String,
+    #[form(compute = "first.len() + last.len()")]
+    letters: usize,
+}
+
+#[test]
+fn computed_fields() {
+    let form: Result<Person, _> = strict("first=Ada&family=Lovelace");
+    assert_eq!(form, Ok(Person {
+        first: "Ada".into(),
+        last: "Lovelace".into(),
+        full_name: "Ada Lovelace".into(),
+        letters: 11,
+    }));
+
+    // Computed fields are never read from the form.
+    let form: Result<Person, _> = strict("first=Ada&family=Lovelace&full_name=Bob");
+    assert_eq!(form, Err(FormParseError::Unknown("full_name".into(), "Bob".into())));
+
+    let form: Result<Person, _> = lenient("full_name=Bob&first=Ada&family=Lovelace&letters=3");
+    assert_eq!(form.map(|p| (p.full_name, p.letters)), Ok(("Ada Lovelace".into(), 11)));
+
+    let form: Result<Person, _> = strict("first=Ada");
+    assert_eq!(form, Err(FormParseError::Missing("family".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+#[form(require = "name, mail, phone")]
+struct Contact {
+    name: String,
+    #[form(field = "mail")]
+    email: String,
+    phone: Option<String>,
+    note: Option<String>,
+}
+
+#[test]
+fn required_fields() {
+    let form: Result<Contact, _> = strict("name=Bob&mail=b@c&phone=123");
+    assert_eq!(form, Ok(Contact {
+        name: "Bob".into(),
+        email: "b@c".into(),
+        phone: Some("123".into()),
+        note: None,
+    }));
+
+    // All missing required fields are reported in a single error.
+    let form: Result<Contact, _> = strict("mail=b@c&note=hi");
+    let fields = match form {
+        Err(FormParseError::MissingRequired(fields)) => fields,
+        other => panic!("unexpected result: {:?}", other),
+    };
+
+    assert_eq!(fields.missing().collect::<Vec<_>>(), vec!["name", "phone"]);
+    assert!(fields.is_missing("phone") && !fields.is_missing("mail"));
+    assert_eq!(FormParseError::MissingRequired(fields).to_string(),
+        "missing required fields: name, phone");
+
+    // Bad values are still reported first.
+    let form: Result<Contact, _> = strict("mail=b@c&note=hi&extra=1");
+    assert_eq!(form, Err(FormParseError::Unknown("extra".into(), "1".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Booking {
+    #[form(format = "%m/%d/%Y")]
+    arrival: time::Date,
+    #[form(format = "%m/%d/%Y")]
+    departure: Option<time::Date>,
+    #[form(field = "time", format = "%I:%M %p")]
+    check_in: time::Time,
+    #[form(format = "%d.%m.%Y %H:%M%z")]
+    booked: Option<time::OffsetDateTime>,
+}
+
+#[test]
+fn formatted_fields() {
+    let date = |y, m, d| time::Date::try_from_ymd(y, m, d).unwrap();
+    let form: Result<Booking, _> = strict("arrival=03%2F01%2F2021&time=02:30+PM");
+    assert_eq!(form, Ok(Booking {
+        arrival: date(2021, 3, 1),
+        departure: None,
+        check_in: time::Time::try_from_hms(14, 30, 0).unwrap(),
+        booked: None,
+    }));
+
+    let form: Result<Booking, _> = strict("arrival=03/01/2021&departure=03/05/2021\
+        &time=09:00+AM&booked=28.02.2021+18:45%2B0100");
+    let booked = date(2021, 2, 28).try_with_hms(18, 45, 0).unwrap()
+        .assume_offset(time::UtcOffset::east_hours(1));
+
+    assert_eq!(form, Ok(Booking {
+        arrival: date(2021, 3, 1),
+        departure: Some(date(2021, 3, 5)),
+        check_in: time::Time::try_from_hms(9, 0, 0).unwrap(),
+        booked: Some(booked),
+    }));
+
+    // Values in any other format, including ISO 8601, are bad values.
+    let form: Result<Booking, _> = strict("arrival=2021-03-01&time=09:00+AM");
+    assert_eq!(form, Err(FormParseError::BadValue("arrival".into(), "2021-03-01".into())));
+
+    // Except for optional fields, which become `None`.
+    let form: Result<Booking, _> = strict("arrival=03/01/2021&departure=5.3.2021&time=09:00+AM");
+    assert_eq!(form.map(|booking| booking.departure), Ok(None));
+
+    let form: Result<Booking, _> = strict("arrival=03/01/2021");
+    assert_eq!(form, Err(FormParseError::Missing("time".into())));
+}
+
+fn white() -> Rgb {
+    Rgb(255, 255, 255)
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Search {
+    query: String,
+    #[form(default = "20")]
+    limit: usize,
+    #[form(field = "sort", default = r#""relevance".to_string()"#)]
+    order: String,
+    #[form(default = "Some(true)")]
+    safe: Option<bool>,
+    #[form(parse_with = "hex_color", default_with = "white", default_if = "none")]
+    color: Rgb,
+}
+
+#[test]
+fn field_defaults() {
+    let form: Result<Search, _> = strict("query=rust");
+    assert_eq!(form, Ok(Search {
+        query: "rust".into(),
+        limit: 20,
+        order: "relevance".into(),
+        safe: Some(true),
+        color: Rgb(255, 255, 255),
+    }));
+
+    let form: Result<Search, _> = strict("query=rust&limit=5&sort=date&safe=false&color=000000");
+    assert_eq!(form, Ok(Search {
+        query: "rust".into(),
+        limit: 5,
+        order: "date".into(),
+        safe: Some(false),
+        color: Rgb(0, 0, 0),
+    }));
+
+    // The sentinel resets the field to its custom default.
+    let form: Result<Search, _> = strict("query=rust&color=none");
+    assert_eq!(form.map(|search| search.color), Ok(Rgb(255, 255, 255)));
+
+    // Present but invalid values and unknown fields are still errors.
+    let form: Result<Search, _> = strict("query=rust&limit=many");
+    assert_eq!(form, Err(FormParseError::BadValue("limit".into(), "many".into())));
+
+    let form: Result<Search, _> = strict("query=rust&page=2");
+    assert_eq!(form, Err(FormParseError::Unknown("page".into(), "2".into())));
+
+    let form: Result<Search, _> = lenient("query=rust&page=2");
+    assert_eq!(form.map(|search| search.limit), Ok(20));
+
+    let form: Result<Search, _> = strict("limit=5");
+    assert_eq!(form, Err(FormParseError::Missing("query".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct TrimmedForm {
+    #[form(trim)]
+    name: String,
+    #[form(trim)]
+    age: Option<u8>,
+    #[form(trim, transform = "lowercase")]
+    email: String,
+    #[form(trim, parse_with = "hex_color")]
+    color: Rgb,
+    untrimmed: String,
+}
+
+#[test]
+fn trimmed_fields() {
+    let form: Result<TrimmedForm, _> = strict("name=%20%20bob%20&age=+42+&email=%09Bob@Example.com%0A\
+        &color=+ff8000&untrimmed=+x+");
+    assert_eq!(form, Ok(TrimmedForm {
+        name: "bob".into(),
+        age: Some(42),
+        email: "bob@example.com".into(),
+        color: Rgb(255, 128, 0),
+        untrimmed: " x ".into(),
+    }));
+
+    // Values of only whitespace become empty; inner whitespace is kept.
+    let form: Result<TrimmedForm, _> = strict("name=+++&age=&email=a+b&color=000000&untrimmed=");
+    assert_eq!(form, Ok(TrimmedForm {
+        name: "".into(),
+        age: None,
+        email: "a b".into(),
+        color: Rgb(0, 0, 0),
+        untrimmed: "".into(),
+    }));
+
+    // Trimmed values are still parsed, and rejected, as usual.
+    let form: Result<TrimmedForm, _> = strict("name=bob&age=+4+2+&email=b&color=ff80+00&untrimmed=");
+    assert_eq!(form, Err(FormParseError::BadValue("color".into(), "ff80+00".into())));
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct TrimmedSentinelForm {
+    #[form(trim, default_if = "none")]
+    nickname: Option<String>,
+}
+
+#[test]
+fn trimmed_sentinel() {
+    let form: Result<TrimmedSentinelForm, _> = strict("nickname=+none%20");
+    assert_eq!(form, Ok(TrimmedSentinelForm { nickname: None }));
+
+    let form: Result<TrimmedSentinelForm, _> = strict("nickname=+nobody+");
+    assert_eq!(form, Ok(TrimmedSentinelForm { nickname: Some("nobody".into()) }));
+}
@@ -1,4 +1,4 @@
-use rocket::request::FromFormValue;
+use rocket::request::{FromFormValue, UnknownValue};
 
 macro_rules! assert_parse {
     ($($string:expr),* => $item:ident :: $variant:ident) => ($(
@@ -79,3 +79,119 @@ fn from_form_value_raw() {
     assert_parse!("this" => Keyword::this);
     assert_no_parse!("r#type" => Keyword);
 }
+
+#[test]
+fn from_form_value_error_lists_values() {
+    #[derive(Debug, FromFormValue)]
+    enum Foo {
+        A,
+        #[form(value = ":book")]
+        Book,
+        OtherA,
+    }
+
+    let error = Foo::from_form_value("abc".into()).unwrap_err();
+    assert_eq!(error, UnknownValue { value: "abc".into(), expected: &["A", ":book", "OtherA"] });
+    assert_eq!(error.to_string(), "unknown value \"abc\"; expected one of: A, :book, OtherA");
+
+    let error = Foo::from_form_value("not%20a%20book".into()).unwrap_err();
+    assert_eq!(error.value, "not%20a%20book");
+    assert_eq!(error.to_string(),
+        "unknown value \"not a book\"; expected one of: A, :book, OtherA");
+}
+
+#[test]
+fn from_form_value_from_str_fallback() {
+    #[derive(Debug, FromFormValue)]
+    #[form(from_str)]
+    enum Toggle {
+        On,
+        Off,
+        #[form(value = "1")]
+        Default,
+    }
+
+    impl std::str::FromStr for Toggle {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, ()> {
+            match s {
+                "1" | "yes" => Ok(Toggle::On),
+                "0" | "no" | "not now" => Ok(Toggle::Off),
+                _ => Err(()),
+            }
+        }
+    }
+
+    assert_parse!("on", "ON", "yes" => Toggle::On);
+    assert_parse!("off", "no", "0", "not%20now" => Toggle::Off);
+
+    // Variant strings take precedence over `FromStr`.
+    assert_parse!("1" => Toggle::Default);
+
+    assert_no_parse!("YES", "maybe", "" => Toggle);
+    let error = Toggle::from_form_value("maybe".into()).unwrap_err();
+    assert_eq!(error.expected, &["On", "Off", "1"]);
+}
+
+#[test]
+fn from_form_value_as_str() {
+    #[allow(non_camel_case_types)]
+    #[derive(Debug, FromFormValue)]
+    enum Category {
+        #[form(value = "CS")]
+        ComputerScience,
+        Biology,
+        r#type,
+    }
+
+    assert_eq!(Category::ComputerScience.as_str(), "CS");
+    assert_eq!(Category::Biology.as_str(), "Biology");
+    assert_eq!(Category::r#type.as_str(), "type");
+
+    // The canonical value parses back to the same variant.
+    for category in &[Category::ComputerScience, Category::Biology, Category::r#type] {
+        let value = category.as_str();
+        assert_eq!(Category::from_form_value(value.into()).unwrap().as_str(), value);
+    }
+}
+
+#[test]
+fn from_form_value_numeric_with_default() {
+    use rocket::request::{FromForm, FormItems, FormParseError};
+
+    #[derive(Debug, PartialEq, FromFormValue)]
+    #[form(numeric, default = "Medium")]
+    enum Level {
+        Low,
+        #[form(value = "mid")]
+        Medium,
+        High = 10,
+    }
+
+    assert_parse!("low", "LOW", "0" => Level::Low);
+    assert_parse!("mid", "1", "" => Level::Medium);
+    assert_parse!("high", "10", "%31%30" => Level::High);
+    assert_no_parse!("medium", "2", "-1", "1.0", " " => Level);
+    assert_eq!(Level::default(), Some(Level::Medium));
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Filter {
+        level: Level,
+    }
+
+    let parse = |string| Filter::from_form(&mut FormItems::from(string), true);
+    assert_eq!(parse(""), Ok(Filter { level: Level::Medium }));
+    assert_eq!(parse("level="), Ok(Filter { level: Level::Medium }));
+    assert_eq!(parse("level=low"), Ok(Filter { level: Level::Low }));
+    assert_eq!(parse("level=10"), Ok(Filter { level: Level::High }));
+    assert_eq!(parse("level=extreme"),
+        Err(FormParseError::BadValue("level".into(), "extreme".into())));
+
+    // Without `default`, absent fields are missing, and only names match.
+    #[derive(Debug, PartialEq, FromFormValue)]
+    enum Plain { A, B }
+
+    assert_no_parse!("", "0", "1" => Plain);
+    assert_eq!(Plain::default(), None);
+}
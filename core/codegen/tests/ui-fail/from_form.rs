@@ -141,4 +141,62 @@ struct BadName3 {
     field: String,
 }
 
+#[derive(FromForm)]
+#[form(require =
+    "0,1,2,3,4,5,6,7,8,9,a,b,c,d,e,f,g,h,i,j,k,l,m,n,o,p,q,r,s,t,u,v,w,x,y,z,A,B,C,D,E,F,G,H,I,J,K,L,M,N,O,P,Q,R,S,T,U,V,W,X,Y,Z,_,-,.")]
+struct TooManyRequired {
+    field: String,
+}
+
+#[derive(FromForm)]
+#[form(require = "name, email")]
+struct UnknownRequired {
+    name: String,
+}
+
+#[derive(FromForm)]
+struct TrimBorrowed<'f> {
+    #[form(trim)]
+    field: &'f RawStr,
+}
+
+#[derive(FromForm)]
+struct TransformBorrowed<'f> {
+    #[form(transform = "str::trim")]
+    field: &'f RawStr,
+}
+
+#[derive(FromForm)]
+struct ComputeWithOthers {
+    name: String,
+    #[form(compute = "name.len()", field = "length")]
+    len: usize,
+}
+
+#[derive(FromForm)]
+struct BadDecimal {
+    #[form(decimal = ";")]
+    price: f64,
+}
+
+#[derive(FromForm)]
+struct FormatWithParseWith {
+    #[form(format = "%Y", parse_with = "parse_year")]
+    year: u16,
+}
+
+#[derive(FromForm)]
+#[form(tag = "kind")]
+enum DuplicateTag {
+    Circle { radius: f64 },
+    #[form(value = "circle")]
+    Disc { radius: f64 },
+}
+
+#[derive(FromForm)]
+#[form(tag = "kind")]
+enum TagConflict {
+    Circle { kind: String },
+}
+
 fn main() { }
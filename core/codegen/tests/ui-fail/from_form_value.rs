@@ -36,4 +36,11 @@ enum Bar2 {
     A,
 }
 
+#[derive(FromFormValue)]
+#[form(default = "C")]
+enum Bar3 {
+    A,
+    B,
+}
+
 fn main() { }
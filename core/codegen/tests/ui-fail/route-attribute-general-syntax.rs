@@ -107,4 +107,9 @@ fn f3() {}
 #[route(120, "/")]
 fn f4() {}
 
+// Check that `assume_format` requires a `format`.
+
+#[post("/", assume_format = true)]
+fn g0() {}
+
 fn main() {}
@@ -11,7 +11,7 @@ enum Foo3 { }
 
 #[derive(UriDisplayQuery)]
 enum Foo4 {
-    Variant,
+    Variant, Other(usize),
 }
 
 #[derive(UriDisplayQuery)]
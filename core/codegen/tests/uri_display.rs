@@ -120,6 +120,45 @@ macro_rules! assert_uri_display_path {
     )
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Clone, Copy, FromFormValue, UriDisplayQuery)]
+enum Kind {
+    Plain,
+    r#type,
+    #[form(value = ":book")]
+    Book,
+    #[form(value = "top rated")]
+    TopRated,
+    #[form(value = "a&b=c")]
+    Ampersand,
+}
+
+#[get("/?<kind>")]
+fn kinds(kind: Kind) -> String {
+    format!("{:?}", kind)
+}
+
+#[test]
+fn uri_display_unit_enum() {
+    assert_uri_display_query!(Kind::Plain, "Plain");
+    assert_uri_display_query!(Kind::r#type, "type");
+    assert_uri_display_query!(Kind::Book, ":book");
+    assert_uri_display_query!(Kind::TopRated, "top%20rated");
+    assert_uri_display_query!(Kind::Ampersand, "a%26b%3Dc");
+}
+
+#[test]
+fn unit_enum_uri_round_trip() {
+    use rocket::request::FromFormValue;
+
+    let variants = [Kind::Plain, Kind::r#type, Kind::Book, Kind::TopRated, Kind::Ampersand];
+    for &variant in variants.iter() {
+        let uri = uri!(kinds: variant);
+        let value = uri.query().and_then(|q| q.strip_prefix("kind=")).expect("kind=");
+        assert_eq!(Kind::from_form_value(value.into()), Ok(variant), "{}", uri);
+    }
+}
+
 #[derive(UriDisplayPath)]
 struct FooP(&'static str);
 
@@ -18,6 +18,12 @@ fn main() {
         println!("cargo:warning={}", "Build may fail due to incompatible rustc version.");
     }
 
+    // `std::num::Saturating` was stabilized in 1.74.
+    println!("cargo:rustc-check-cfg=cfg(saturating)");
+    if let Some(true) = version_check::is_min_version("1.74.0") {
+        println!("cargo:rustc-cfg=saturating");
+    }
+
     if let Some(true) = version_check::is_feature_flaggable() {
         println!("cargo:rustc-cfg=nightly");
     }
@@ -1,3 +1,6 @@
+use std::cell::UnsafeCell;
+use std::sync::Once;
+
 use crate::{Request, Data};
 use crate::handler::HandlerFuture;
 use crate::catcher::ErrorHandlerFuture;
@@ -19,6 +22,8 @@ pub struct StaticRouteInfo {
     pub path: &'static str,
     /// The route's format, if any.
     pub format: Option<MediaType>,
+    /// Whether the route's format is assumed for requests without one.
+    pub assume_format: bool,
     /// The route's handler, i.e, the annotated function.
     pub handler: StaticHandler,
     /// The route's rank, if any.
@@ -32,3 +37,29 @@ pub struct StaticCatcherInfo {
     /// The catcher's handler, i.e, the annotated function.
     pub handler: StaticErrorHandler,
 }
+
+/// A value initialized on first use, stored in a `static`. The `FromForm`
+/// derive uses it to build each `parse_with` parser only once.
+pub struct Lazy<T> {
+    once: Once,
+    value: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: `value` is written once, inside `call_once()`, and is only read
+// after `call_once()` returns, so shared access from any thread is safe.
+unsafe impl<T: Send + Sync> Sync for Lazy<T> { }
+
+impl<T> Lazy<T> {
+    /// Creates an uninitialized `Lazy`.
+    pub const fn new() -> Lazy<T> {
+        Lazy { once: Once::new(), value: UnsafeCell::new(None) }
+    }
+
+    /// Returns the value, initializing it with `init` if this is the first
+    /// call.
+    pub fn get_or_init<F: FnOnce() -> T>(&self, init: F) -> &T {
+        // SAFETY: See the `Sync` implementation.
+        self.once.call_once(|| unsafe { *self.value.get() = Some(init()) });
+        unsafe { (*self.value.get()).as_ref().expect("initialized by `call_once()`") }
+    }
+}
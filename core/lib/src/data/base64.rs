@@ -0,0 +1,162 @@
+use std::io;
+use std::ops::{Deref, DerefMut};
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{ByteUnit, Data, FromData, LimitExceeded, Outcome, ToByteUnit};
+use crate::http::Status;
+
+/// A data guard that transparently decodes base64-encoded request bodies.
+///
+/// `Base64<T>` wraps any [`FromData`] guard `T`. If the incoming request
+/// contains a `Content-Transfer-Encoding: base64` header, the body is read and
+/// base64 decoded, and the decoded bytes are handed to `T`. Otherwise, the raw
+/// body is passed to `T` untouched. This is useful for APIs that bridge
+/// text-only transports.
+///
+/// ASCII whitespace in an encoded body, such as the line breaks inserted by
+/// MIME encoders, is ignored.
+///
+/// # Limits
+///
+/// The size of the _decoded_ body is limited by the `base64` limit, which
+/// defaults to 1MiB. If the encoded body, excluding whitespace, is longer than
+/// required to encode a body of that size, or if the body including whitespace
+/// is over twice that length, the guard fails with status `PayloadTooLarge`
+/// and a [`Base64Error::TooLarge`]. The body is never truncated. The inner
+/// guard `T` continues to apply its own limits to the decoded data.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # use rocket::data::{self, FromData, Data};
+/// # use rocket::request::Request;
+/// # struct Upload;
+/// # #[rocket::async_trait]
+/// # impl FromData for Upload {
+/// #     type Error = ();
+/// #     async fn from_data(_: &Request<'_>, _: Data) -> data::Outcome<Self, ()> {
+/// #         data::Outcome::Success(Upload)
+/// #     }
+/// # }
+/// use rocket::data::Base64;
+///
+/// #[post("/upload", data = "<upload>")]
+/// fn upload(upload: Base64<Upload>) {
+///     let upload: Upload = upload.into_inner();
+///     /* .. */
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct Base64<T>(pub T);
+
+/// Error returned by the [`FromData`] implementation of [`Base64`].
+#[derive(Debug)]
+pub enum Base64Error<E> {
+    /// An I/O error occurred while reading the encoded data stream.
+    Io(io::Error),
+    /// The request body was not valid base64.
+    Decode,
+    /// The decoded request body would exceed the `base64` limit, `.0`.
+    TooLarge(ByteUnit),
+    /// The inner guard failed with the contained error.
+    Inner(E),
+}
+
+impl<T> Base64<T> {
+    /// Consumes `self` and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Base64<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Base64<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+fn is_base64_encoded(request: &Request<'_>) -> bool {
+    request.headers()
+        .get_one("Content-Transfer-Encoding")
+        .map_or(false, |v| v.trim().eq_ignore_ascii_case("base64"))
+}
+
+fn strip_whitespace(encoded: &[u8]) -> Vec<u8> {
+    encoded.iter()
+        .filter(|b| !b.is_ascii_whitespace())
+        .cloned()
+        .collect()
+}
+
+fn decode(encoded: &[u8]) -> Option<Vec<u8>> {
+    // `binascii` requires space for the full 3 bytes of every 4-byte block.
+    let mut buf = vec![0u8; encoded.len() / 4 * 3];
+    let len = binascii::b64decode(&encoded, &mut buf).ok()?.len();
+    buf.truncate(len);
+    Some(buf)
+}
+
+/// Base64 decodes the body if the request indicates that the body is base64
+/// encoded, then delegates to `T`. If reading the body fails, returns a
+/// `Failure` with status `InternalServerError`. If the body exceeds the limit,
+/// returns a `Failure` with status `PayloadTooLarge`. If the body is not valid
+/// base64, returns a `Failure` with status `BadRequest`.
+#[crate::async_trait]
+impl<T: FromData> FromData for Base64<T> {
+    type Error = Base64Error<T::Error>;
+
+    async fn from_data(req: &Request<'_>, data: Data) -> Outcome<Self, Self::Error> {
+        if !is_base64_encoded(req) {
+            return match T::from_data(req, data).await {
+                Success(v) => Success(Base64(v)),
+                Forward(d) => Forward(d),
+                Failure((s, e)) => Failure((s, Base64Error::Inner(e))),
+            };
+        }
+
+        let limit = req.limits().get("base64").unwrap_or(1.mebibytes());
+        let encoded_limit = (limit.as_u64().saturating_add(2) / 3).saturating_mul(4);
+        let raw_limit = encoded_limit.saturating_mul(2);
+        let too_large = || {
+            error_!("Base64 data exceeds limit ({}).", limit);
+            Failure((Status::PayloadTooLarge, Base64Error::TooLarge(limit)))
+        };
+
+        let encoded = match data.open_within(raw_limit.into()).stream_to_vec().await {
+            Ok(encoded) => encoded,
+            Err(e) if LimitExceeded::of(&e).is_some() => return too_large(),
+            Err(e) => return Failure((Status::InternalServerError, Base64Error::Io(e))),
+        };
+
+        let stripped = strip_whitespace(&encoded);
+        if stripped.len() as u64 > encoded_limit {
+            return too_large();
+        }
+
+        let decoded = match decode(&stripped) {
+            Some(decoded) => decoded,
+            None => {
+                error_!("The request body was not valid base64.");
+                return Failure((Status::BadRequest, Base64Error::Decode));
+            }
+        };
+
+        match T::from_data(req, Data::local(decoded)).await {
+            Success(v) => Success(Base64(v)),
+            Forward(_) => Forward(Data::local(encoded)),
+            Failure((s, e)) => Failure((s, Base64Error::Inner(e))),
+        }
+    }
+}
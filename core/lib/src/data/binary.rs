@@ -0,0 +1,140 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{Data, DataStream, FromData, Outcome, ToByteUnit};
+use crate::http::Status;
+
+/// Trait implemented by types that can be parsed from a binary byte stream.
+///
+/// Implementations read as much of `reader` as they need to produce a value.
+/// The reader is an [`AsyncRead`](crate::tokio::io::AsyncRead), so
+/// [`AsyncReadExt`](crate::tokio::io::AsyncReadExt) methods like `read_u32()`
+/// and `read_exact()` can be used directly. Reading beyond the applicable limit
+/// returns an error; implementations should propagate it, and the request is
+/// then rejected regardless of the error value returned.
+///
+/// Implementations are used by the [`Binary`] data guard.
+///
+/// # Example
+///
+/// A message with a big-endian, `u16` length prefix:
+///
+/// ```rust
+/// use rocket::data::{DataStream, FromReader};
+/// use rocket::tokio::io::AsyncReadExt;
+///
+/// struct Message(Vec<u8>);
+///
+/// #[rocket::async_trait]
+/// impl FromReader for Message {
+///     type Error = std::io::Error;
+///
+///     async fn from_reader(reader: &mut DataStream) -> Result<Self, Self::Error> {
+///         let len = reader.read_u16().await?;
+///         let mut bytes = vec![0; len as usize];
+///         reader.read_exact(&mut bytes).await?;
+///         Ok(Message(bytes))
+///     }
+/// }
+/// ```
+#[crate::async_trait]
+pub trait FromReader: Sized {
+    /// The associated error to be returned when parsing fails.
+    type Error: Send + 'static;
+
+    /// Parses an instance of `Self` from `reader`.
+    async fn from_reader(reader: &mut DataStream) -> Result<Self, Self::Error>;
+}
+
+/// A data guard that parses `application/octet-stream` request bodies with a
+/// [`FromReader`] implementation.
+///
+/// `Binary<T>` is to binary protocols what `Json<T>` is to JSON: if the
+/// incoming request has a `Content-Type` of `application/octet-stream`, the
+/// body stream is handed to `T::from_reader()`. Otherwise, the request is
+/// forwarded.
+///
+/// # Limits
+///
+/// The body stream is limited by the `binary` limit, which defaults to 1MiB.
+/// If the parser reads past the limit, the request fails with status
+/// `PayloadTooLarge`. Data past the limit that the parser never reads is
+/// ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # use rocket::data::{DataStream, FromReader};
+/// # struct Message(Vec<u8>);
+/// # #[rocket::async_trait]
+/// # impl FromReader for Message {
+/// #     type Error = ();
+/// #     async fn from_reader(_: &mut DataStream) -> Result<Self, ()> {
+/// #         Ok(Message(vec![]))
+/// #     }
+/// # }
+/// use rocket::data::Binary;
+///
+/// #[post("/message", data = "<message>")]
+/// fn message(message: Binary<Message>) {
+///     let message: Message = message.into_inner();
+///     /* .. */
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct Binary<T>(pub T);
+
+impl<T> Binary<T> {
+    /// Consumes `self` and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Binary<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Binary<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Forwards the request if its content type is not `application/octet-stream`.
+/// Otherwise, parses the body with `T`'s [`FromReader`] implementation. If
+/// parsing reads past the `binary` limit, returns a `Failure` with status
+/// `PayloadTooLarge`. If parsing otherwise fails, returns a `Failure` with
+/// status `UnprocessableEntity`.
+#[crate::async_trait]
+impl<T: FromReader> FromData for Binary<T> {
+    type Error = T::Error;
+
+    async fn from_data(req: &Request<'_>, data: Data) -> Outcome<Self, Self::Error> {
+        if !req.content_type().map_or(false, |ct| ct.is_binary()) {
+            return Forward(data);
+        }
+
+        let limit = req.limits().get("binary").unwrap_or(1.mebibytes());
+        let mut reader = data.open_within(limit);
+        match T::from_reader(&mut reader).await {
+            Ok(value) => Success(Binary(value)),
+            Err(e) if reader.limit_exceeded() => {
+                error_!("The binary request body exceeds the limit of {}.", limit);
+                Failure((Status::PayloadTooLarge, e))
+            }
+            Err(e) => {
+                error_!("The binary request body failed to parse.");
+                Failure((Status::UnprocessableEntity, e))
+            }
+        }
+    }
+}
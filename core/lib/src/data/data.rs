@@ -4,6 +4,7 @@ use crate::http::hyper;
 use crate::ext::AsyncReadBody;
 use crate::tokio::io::AsyncReadExt;
 use crate::data::data_stream::DataStream;
+use crate::data::tee::SharedTee;
 use crate::data::ByteUnit;
 
 /// The number of bytes to read into the "peek" buffer.
@@ -45,6 +46,7 @@ pub struct Data {
     buffer: Vec<u8>,
     is_complete: bool,
     stream: AsyncReadBody,
+    tee: Option<SharedTee>,
 }
 
 impl Data {
@@ -55,7 +57,7 @@ impl Data {
 
         let stream = AsyncReadBody::from(body);
         let buffer = Vec::with_capacity(PEEK_BYTES / 8);
-        Data { buffer, stream, is_complete: false }
+        Data { buffer, stream, is_complete: false, tee: None }
     }
 
     /// This creates a `data` object from a local data source `data`.
@@ -65,6 +67,7 @@ impl Data {
             buffer: data,
             stream: AsyncReadBody::empty(),
             is_complete: true,
+            tee: None,
         }
     }
 
@@ -86,11 +89,56 @@ impl Data {
     /// }
     /// ```
     pub fn open(self, limit: ByteUnit) -> DataStream {
+        self.open_unchecked(limit, None)
+    }
+
+    /// Returns the raw data stream, failing if the body exceeds `limit` bytes.
+    ///
+    /// Unlike the stream returned by [`open()`](Data::open()), which silently
+    /// ends at `limit`, reading this stream fails with an [`io::Error`]
+    /// wrapping a [`LimitExceeded`] once more than `limit` bytes are read, so
+    /// that a body over the limit is never mistaken for a complete one. This
+    /// is the case regardless of the request's `Content-Length`, which may be
+    /// absent, as with chunked bodies.
+    ///
+    /// [`io::Error`]: std::io::Error
+    /// [`LimitExceeded`]: crate::data::LimitExceeded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// async fn handler(data: Data) -> io::Result<String> {
+    ///     data.open_within(4.kibibytes()).stream_to_string().await
+    /// }
+    /// ```
+    pub fn open_within(self, limit: ByteUnit) -> DataStream {
+        self.open_unchecked(limit.as_u64().saturating_add(1).into(), Some(limit))
+    }
+
+    /// Opens the stream, reading at most `limit` bytes. A stream opened with
+    /// `within` fails if `limit` bytes are read.
+    fn open_unchecked(self, limit: ByteUnit, within: Option<ByteUnit>) -> DataStream {
         let buffer_limit = std::cmp::min(self.buffer.len().into(), limit);
         let stream_limit = limit - buffer_limit;
         let buffer = Cursor::new(self.buffer).take(buffer_limit.into());
         let stream = self.stream.take(stream_limit.into());
-        DataStream { buffer, stream }
+        DataStream { buffer, stream, progress: None, tee: self.tee, within }
+    }
+
+    /// Archives all data subsequently read from the stream returned by
+    /// `open()` to `sink`. Returns `false`, and leaves `self` unchanged, if the
+    /// data is already being archived.
+    pub(crate) fn tee(&mut self, sink: SharedTee) -> bool {
+        match self.tee {
+            Some(_) => false,
+            None => {
+                self.tee = Some(sink);
+                true
+            }
+        }
     }
 
     /// Retrieve at most `num` bytes from the `peek` buffer without consuming
@@ -1,11 +1,14 @@
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::path::Path;
+use std::fmt;
 use std::io::{self, Cursor};
 
 use tokio::io::{AsyncRead, AsyncWrite, AsyncReadExt, ReadBuf, Take};
 
 use crate::ext::AsyncReadBody;
+use crate::data::ByteUnit;
+use crate::data::tee::SharedTee;
 
 /// Raw data stream of a request body.
 ///
@@ -15,10 +18,117 @@ use crate::ext::AsyncReadBody;
 /// must be used as an opaque [`AsyncRead`] structure.
 pub struct DataStream {
     pub(crate) buffer: Take<Cursor<Vec<u8>>>,
-    pub(crate) stream: Take<AsyncReadBody>
+    pub(crate) stream: Take<AsyncReadBody>,
+    pub(crate) progress: Option<Progress>,
+    pub(crate) tee: Option<SharedTee>,
+    pub(crate) within: Option<ByteUnit>,
+}
+
+/// Error returned when reading a stream opened with
+/// [`Data::open_within()`](crate::data::Data::open_within()) whose body exceeds
+/// the limit in `.0`.
+///
+/// The error is returned wrapped in an [`io::Error`] of kind
+/// [`io::ErrorKind::Other`]; use [`LimitExceeded::of()`] to retrieve it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LimitExceeded(pub ByteUnit);
+
+impl LimitExceeded {
+    /// Returns the `LimitExceeded` error wrapped by `error`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Data, LimitExceeded, ToByteUnit};
+    ///
+    /// async fn handler(data: Data) -> Result<Vec<u8>, String> {
+    ///     data.open_within(4.kibibytes()).stream_to_vec().await
+    ///         .map_err(|e| match LimitExceeded::of(&e) {
+    ///             Some(LimitExceeded(limit)) => format!("body exceeds {}", limit),
+    ///             None => format!("I/O error: {}", e),
+    ///         })
+    /// }
+    /// ```
+    pub fn of(error: &io::Error) -> Option<LimitExceeded> {
+        error.get_ref()?.downcast_ref::<LimitExceeded>().copied()
+    }
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data exceeds limit of {}", self.0)
+    }
+}
+
+impl std::error::Error for LimitExceeded { }
+
+/// Progress reporting state for a `DataStream`.
+pub(crate) struct Progress {
+    read: u64,
+    reported: u64,
+    interval: u64,
+    total: Option<u64>,
+    callback: Box<dyn FnMut(u64, Option<u64>) + Send>,
+}
+
+impl Progress {
+    /// Records that `n` more bytes were read, invoking the callback if at
+    /// least `interval` bytes were read since the last report or if the stream
+    /// ended (`n == 0`) with unreported bytes.
+    fn advance(&mut self, n: u64) {
+        self.read += n;
+        let unreported = self.read - self.reported;
+        if unreported >= self.interval || (n == 0 && unreported > 0) {
+            self.reported = self.read;
+            (self.callback)(self.read, self.total);
+        }
+    }
 }
 
 impl DataStream {
+    /// Registers `callback` to be invoked as data is read from the stream.
+    ///
+    /// The callback is called with the total number of bytes read so far and
+    /// `total`, the expected total number of bytes, if known, typically taken
+    /// from the request's `Content-Length` header. It is invoked each time at
+    /// least `interval` bytes have been read since it was last invoked and,
+    /// with the final count, when the stream ends. The reported counts are
+    /// strictly increasing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use rocket::request::Request;
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// async fn handler(req: &Request<'_>, data: Data) -> io::Result<u64> {
+    ///     let total = req.headers().get_one("Content-Length")
+    ///         .and_then(|length| length.parse().ok());
+    ///
+    ///     data.open(128.mebibytes())
+    ///         .on_progress(1.mebibytes(), total, |read, total| match total {
+    ///             Some(total) => println!("upload: {}/{} bytes", read, total),
+    ///             None => println!("upload: {} bytes", read),
+    ///         })
+    ///         .stream_to_file("/tmp/upload")
+    ///         .await
+    /// }
+    /// ```
+    pub fn on_progress<F>(mut self, interval: ByteUnit, total: Option<u64>, callback: F) -> Self
+        where F: FnMut(u64, Option<u64>) + Send + 'static
+    {
+        self.progress = Some(Progress {
+            read: 0,
+            reported: 0,
+            interval: std::cmp::max(interval.as_u64(), 1),
+            total,
+            callback: Box::new(callback),
+        });
+
+        self
+    }
+
     /// A helper method to write the body of the request to any `AsyncWrite`
     /// type.
     ///
@@ -68,6 +178,12 @@ impl DataStream {
 
     /// A helper method to write the body of the request to a `String`.
     ///
+    /// If the data read is not valid UTF-8, an error of kind `InvalidData` is
+    /// returned. Use [`stream_to_capped_string()`] to learn whether the limit
+    /// cut the body short.
+    ///
+    /// [`stream_to_capped_string()`]: DataStream::stream_to_capped_string()
+    ///
     /// # Example
     ///
     /// ```rust
@@ -87,6 +203,51 @@ impl DataStream {
         Ok(string)
     }
 
+    /// A helper method to write the body of the request to a `String`,
+    /// returning it along with whether the entire body was read.
+    ///
+    /// The flag is `false` if the body continues past the limit. In that case,
+    /// if the limit falls in the middle of a multi-byte UTF-8 character, the
+    /// string is truncated to the last complete character within the limit.
+    /// Otherwise, if the data read is not valid UTF-8, an error of kind
+    /// `InvalidData` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// async fn handler(data: Data) -> io::Result<String> {
+    ///     match data.open(10.bytes()).stream_to_capped_string().await? {
+    ///         (string, true) => Ok(string),
+    ///         (string, false) => Ok(format!("{}...", string)),
+    ///     }
+    /// }
+    /// ```
+    pub async fn stream_to_capped_string(mut self) -> io::Result<(String, bool)> {
+        let buf_len = self.buffer.get_ref().get_ref().len();
+        let max_from_buf = std::cmp::min(buf_len, self.buffer.limit() as usize);
+        let capacity = std::cmp::min(max_from_buf, 1024);
+        let mut bytes = Vec::with_capacity(capacity);
+        self.read_to_end(&mut bytes).await?;
+
+        let complete = !self.has_more().await?;
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+        match String::from_utf8(bytes) {
+            Ok(string) => Ok((string, complete)),
+            // The limit split a character: `error_len()` is `None` only when
+            // the invalid bytes are an incomplete sequence at the very end.
+            Err(e) if !complete && e.utf8_error().error_len().is_none() => {
+                let valid_up_to = e.utf8_error().valid_up_to();
+                let mut bytes = e.into_bytes();
+                bytes.truncate(valid_up_to);
+                String::from_utf8(bytes).map(|string| (string, false)).map_err(invalid)
+            }
+            Err(e) => Err(invalid(e)),
+        }
+    }
+
     /// A helper method to write the body of the request to a `Vec<u8>`.
     ///
     /// # Example
@@ -117,6 +278,58 @@ impl AsyncRead for DataStream {
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let start = buf.filled().len();
+        let poll = self.as_mut().poll_read_inner(cx, buf);
+
+        // Streams opened with `open_within()` are opened with one byte past the
+        // limit; if that byte is read, the body exceeds the limit.
+        if let (Poll::Ready(Ok(())), Some(limit)) = (&poll, self.within) {
+            if self.limit_exceeded() {
+                let error = io::Error::new(io::ErrorKind::Other, LimitExceeded(limit));
+                return Poll::Ready(Err(error));
+            }
+        }
+        if let (Poll::Ready(Ok(())), Some(progress)) = (&poll, self.progress.as_mut()) {
+            progress.advance((buf.filled().len() - start) as u64);
+        }
+
+        if let (Poll::Ready(Ok(())), Some(tee)) = (&poll, self.tee.as_ref()) {
+            if let Err(e) = tee.lock().expect("tee sink lock").send(&buf.filled()[start..]) {
+                return Poll::Ready(Err(e));
+            }
+        }
+
+        poll
+    }
+}
+
+impl DataStream {
+    /// Returns `true` if this stream was opened with `open_within()` and a read
+    /// has gone past the limit.
+    pub(crate) fn limit_exceeded(&self) -> bool {
+        self.within.is_some() && self.buffer.limit() == 0 && self.stream.limit() == 0
+    }
+
+    /// Returns `true` if the body continues past the limit. Must only be called
+    /// once the stream has been read to its end.
+    async fn has_more(&mut self) -> io::Result<bool> {
+        if self.buffer.limit() > 0 || self.stream.limit() > 0 {
+            return Ok(false);
+        }
+
+        let buffer = self.buffer.get_ref();
+        if buffer.position() < buffer.get_ref().len() as u64 {
+            return Ok(true);
+        }
+
+        Ok(self.stream.get_mut().read(&mut [0; 1]).await? > 0)
+    }
+
+    fn poll_read_inner(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
     ) -> Poll<io::Result<()>> {
         if self.buffer.limit() > 0 {
             trace_!("DataStream::buffer_read()");
@@ -10,8 +10,12 @@ use crate::data::{ByteUnit, ToByteUnit};
 /// A `Limits` structure contains a mapping from a given data type ("forms",
 /// "json", and so on) to the maximum size in bytes that should be accepted by a
 /// Rocket application for that data type. For instance, if the limit for
-/// "forms" is set to `256`, only 256 bytes from an incoming form request will
-/// be read.
+/// "forms" is set to `256`, an incoming form request whose body exceeds 256
+/// bytes is rejected with status `413 Payload Too Large`. How a limit is
+/// enforced is up to the data guard that reads the body: see
+/// [`Data::open()`](crate::data::Data::open()), which stops reading at the
+/// limit, and [`Data::open_within()`](crate::data::Data::open_within()), which
+/// fails past it.
 ///
 /// # Defaults
 ///
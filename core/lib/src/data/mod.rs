@@ -1,13 +1,27 @@
 //! Types and traits for handling incoming body data.
 
 mod data;
+mod base64;
+mod binary;
 mod data_stream;
 mod from_data;
 mod limits;
+mod non_empty;
+mod require_content_type;
+mod tee;
+mod tail;
+mod typed;
 
 pub use self::data::Data;
-pub use self::data_stream::DataStream;
+pub use self::data_stream::{DataStream, LimitExceeded};
 pub use self::from_data::{FromData, Outcome, FromTransformedData, FromDataFuture};
 pub use self::from_data::{Transform, Transformed, TransformFuture};
 pub use self::limits::Limits;
+pub use self::non_empty::NonEmpty;
+pub use self::require_content_type::{RequireContentType, RequiredContentType, ContentTypeError};
+pub use self::base64::{Base64, Base64Error};
+pub use self::binary::{Binary, FromReader};
+pub use self::tee::{Tee, TeeError, Archive, TempArchive};
+pub use self::tail::Tail;
+pub use self::typed::{Typed, TypedError, DataSpec};
 pub use ubyte::{ByteUnit, ToByteUnit};
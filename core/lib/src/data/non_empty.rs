@@ -0,0 +1,106 @@
+use std::ops::{Deref, DerefMut};
+
+use futures::future::{ready, FutureExt};
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{Data, Transform, Transformed, TransformFuture};
+use crate::data::{FromTransformedData, FromDataFuture};
+
+/// A data guard that forwards when the request body is empty.
+///
+/// `NonEmpty<T>` wraps any data guard `T`. If the incoming request has no body
+/// data at all, the request is forwarded _without_ invoking `T`. Otherwise,
+/// `T` is invoked as usual and its outcome is returned. This allows routes to
+/// cleanly distinguish "no body" from "invalid body": a `Form<T>` that receives
+/// an empty body typically fails with a parse error, while a
+/// `NonEmpty<Form<T>>` forwards to the next matching route instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::Form;
+/// use rocket::data::NonEmpty;
+///
+/// #[derive(FromForm)]
+/// struct Task {
+///     description: String,
+/// }
+///
+/// #[post("/", data = "<task>")]
+/// fn new(task: NonEmpty<Form<Task>>) -> String {
+///     format!("New task: {}", task.description)
+/// }
+///
+/// #[post("/", rank = 2)]
+/// fn empty() -> &'static str {
+///     "Please submit a task."
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct NonEmpty<T>(pub T);
+
+impl<T> NonEmpty<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::data::{Data, NonEmpty};
+    ///
+    /// #[post("/", data = "<body>")]
+    /// fn upload(body: NonEmpty<Data>) {
+    ///     let data: Data = body.into_inner();
+    /// }
+    /// # fn main() { }
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for NonEmpty<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for NonEmpty<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Forwards if the request body is empty. Otherwise, returns the outcome of
+/// `T`'s `FromTransformedData` implementation.
+impl<'r, T: FromTransformedData<'r> + 'r> FromTransformedData<'r> for NonEmpty<T> {
+    type Error = T::Error;
+    type Owned = T::Owned;
+    type Borrowed = T::Borrowed;
+
+    fn transform(r: &'r Request<'_>, mut d: Data) -> TransformFuture<'r, Self::Owned, Self::Error> {
+        Box::pin(async move {
+            if d.peek(1).await.is_empty() {
+                trace_!("NonEmpty: request body is empty. Forwarding.");
+                return Transform::Owned(Forward(d));
+            }
+
+            T::transform(r, d).await
+        })
+    }
+
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+        match o {
+            Transform::Owned(Forward(d)) | Transform::Borrowed(Forward(d)) => {
+                Box::pin(ready(Forward(d)))
+            }
+            o => Box::pin(T::from_data(r, o).map(|outcome| outcome.map(NonEmpty))),
+        }
+    }
+}
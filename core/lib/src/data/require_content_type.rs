@@ -0,0 +1,189 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use futures::future::{ready, FutureExt};
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{Data, Transform, Transformed, TransformFuture};
+use crate::data::{FromTransformedData, FromDataFuture};
+use crate::http::{ContentType, Status};
+
+/// The `Content-Type` required by a [`RequireContentType`].
+///
+/// Implement this trait on a marker type to choose the content type a
+/// `RequireContentType` accepts. Only the top-level and sub-level types are
+/// compared; parameters, like `charset`, are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::http::ContentType;
+/// use rocket::data::RequiredContentType;
+///
+/// struct JsonBody;
+///
+/// impl RequiredContentType for JsonBody {
+///     fn content_type() -> ContentType {
+///         ContentType::JSON
+///     }
+/// }
+/// ```
+pub trait RequiredContentType {
+    /// Returns the required content type.
+    fn content_type() -> ContentType;
+}
+
+/// A data guard that fails when the request has the wrong `Content-Type`.
+///
+/// `RequireContentType<C, T>` wraps any data guard `T`. If the `Content-Type`
+/// of the incoming request matches the one required by `C`, `T` is invoked as
+/// usual and its outcome is returned. Otherwise, `T` is not invoked, and a
+/// `Failure` with status `UnsupportedMediaType` (415) and a
+/// [`ContentTypeError::Mismatch`] describing the expected and actual content
+/// types is returned. A request without a `Content-Type` never matches.
+///
+/// Data guards like `Form<T>` _forward_ requests with the wrong content type,
+/// which usually results in a `404` when no other route matches. Strict APIs
+/// can use `RequireContentType` to tell clients what went wrong instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::ContentType;
+/// use rocket::request::Form;
+/// use rocket::data::{RequireContentType, RequiredContentType};
+///
+/// struct FormBody;
+///
+/// impl RequiredContentType for FormBody {
+///     fn content_type() -> ContentType {
+///         ContentType::Form
+///     }
+/// }
+///
+/// #[derive(FromForm)]
+/// struct Task {
+///     description: String,
+/// }
+///
+/// #[post("/", data = "<task>")]
+/// fn new(task: RequireContentType<FormBody, Form<Task>>) -> String {
+///     format!("New task: {}", task.description)
+/// }
+/// # fn main() { }
+/// ```
+pub struct RequireContentType<C, T> {
+    value: T,
+    _content_type: PhantomData<fn() -> C>,
+}
+
+/// Error returned by the data guard implementation of [`RequireContentType`].
+#[derive(Debug)]
+pub enum ContentTypeError<E> {
+    /// The request's content type didn't match the required content type.
+    Mismatch {
+        /// The required content type.
+        expected: ContentType,
+        /// The request's content type, if it had one.
+        actual: Option<ContentType>,
+    },
+    /// The inner data guard failed.
+    Inner(E),
+}
+
+impl<C, T> RequireContentType<C, T> {
+    /// Consumes `self` and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<C, T: fmt::Debug> fmt::Debug for RequireContentType<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RequireContentType").field(&self.value).finish()
+    }
+}
+
+impl<C, T> Deref for RequireContentType<C, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<C, T> DerefMut for RequireContentType<C, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ContentTypeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentTypeError::Mismatch { expected, actual: Some(actual) } => {
+                write!(f, "expected Content-Type `{}` but found `{}`", expected, actual)
+            }
+            ContentTypeError::Mismatch { expected, actual: None } => {
+                write!(f, "expected Content-Type `{}` but none was set", expected)
+            }
+            ContentTypeError::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Returns `true` if `request` has a `Content-Type` matching `C`'s.
+pub(crate) fn matches<C: RequiredContentType>(request: &Request<'_>) -> bool {
+    request.content_type().map_or(false, |actual| actual == &C::content_type())
+}
+
+/// Fails with `415` if the request's content type doesn't match `C`'s.
+/// Otherwise, returns the outcome of `T`'s `FromTransformedData`
+/// implementation.
+impl<'r, C, T> FromTransformedData<'r> for RequireContentType<C, T>
+    where C: RequiredContentType + 'r, T: FromTransformedData<'r> + 'r
+{
+    type Error = ContentTypeError<T::Error>;
+    type Owned = T::Owned;
+    type Borrowed = T::Borrowed;
+
+    fn transform(r: &'r Request<'_>, d: Data) -> TransformFuture<'r, Self::Owned, Self::Error> {
+        if !matches::<C>(r) {
+            let (expected, actual) = (C::content_type(), r.content_type().cloned());
+            let error = ContentTypeError::Mismatch { expected, actual };
+            error_!("Unsupported request body: {}.", error);
+            let failure = Failure((Status::UnsupportedMediaType, error));
+            return Box::pin(ready(Transform::Owned(failure)));
+        }
+
+        Box::pin(T::transform(r, d).map(|transform| {
+            let inner = |(status, e)| (status, ContentTypeError::Inner(e));
+            match transform {
+                Transform::Owned(outcome) => Transform::Owned(outcome.map_failure(inner)),
+                Transform::Borrowed(outcome) => Transform::Borrowed(outcome.map_failure(inner)),
+            }
+        }))
+    }
+
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+        let inner = |(status, e)| (status, ContentTypeError::Inner(e));
+        let o = match o {
+            Transform::Owned(Failure(e)) | Transform::Borrowed(Failure(e)) => {
+                return Box::pin(ready(Failure(e)));
+            }
+            Transform::Owned(Forward(d)) => Transform::Owned(Forward(d)),
+            Transform::Borrowed(Forward(d)) => Transform::Borrowed(Forward(d)),
+            Transform::Owned(Success(v)) => Transform::Owned(Success(v)),
+            Transform::Borrowed(Success(v)) => Transform::Borrowed(Success(v)),
+        };
+
+        Box::pin(T::from_data(r, o).map(move |outcome| {
+            outcome.map(|value| RequireContentType { value, _content_type: PhantomData })
+                .map_failure(inner)
+        }))
+    }
+}
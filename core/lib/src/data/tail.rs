@@ -0,0 +1,123 @@
+use std::io;
+use std::ops::Deref;
+use std::collections::VecDeque;
+
+use tokio::io::AsyncReadExt;
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{ByteUnit, Data, FromData, Outcome, ToByteUnit};
+use crate::http::Status;
+
+/// A data guard that retains only the last bytes of the request body.
+///
+/// `Tail` reads the entire body but keeps only its final bytes in a fixed-size
+/// ring buffer, discarding earlier bytes as later ones arrive. This allows
+/// endpoints, such as log collectors, to summarize arbitrarily large bodies
+/// while using a bounded amount of memory. The total number of bytes read is
+/// available via [`Tail::total()`].
+///
+/// # Limits
+///
+/// The number of retained bytes is set by the `tail` limit, which defaults to
+/// 64KiB. To bound memory use, it is capped at [`Tail::MAX`], 1MiB; a larger
+/// configured limit is reduced to `Tail::MAX` with a warning. Because only the
+/// retained bytes are kept in memory, the length of the body itself is not
+/// limited.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::data::Tail;
+///
+/// #[post("/logs", data = "<log>")]
+/// fn logs(log: Tail) -> String {
+///     let last_line = String::from_utf8_lossy(&log).lines().last().unwrap_or("").to_string();
+///     format!("read {} bytes; last line: {}", log.total(), last_line)
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tail {
+    bytes: Vec<u8>,
+    total: u64,
+}
+
+impl Tail {
+    /// The maximum number of bytes a `Tail` retains, regardless of the
+    /// configured `tail` limit: 1MiB.
+    pub const MAX: ByteUnit = ByteUnit::Mebibyte(1);
+
+    /// Returns the retained bytes: the last bytes of the body.
+    #[inline(always)]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the total number of bytes in the body, including those that
+    /// were discarded.
+    #[inline(always)]
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns `true` if bytes at the beginning of the body were discarded.
+    #[inline(always)]
+    pub fn is_truncated(&self) -> bool {
+        self.total > self.bytes.len() as u64
+    }
+
+    /// Consumes `self` and returns the retained bytes.
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl Deref for Tail {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Reads the entire body, retaining its last bytes as set by the `tail` limit.
+/// If reading fails, returns a `Failure` with status `InternalServerError`.
+#[crate::async_trait]
+impl FromData for Tail {
+    type Error = io::Error;
+
+    async fn from_data(req: &Request<'_>, data: Data) -> Outcome<Self, Self::Error> {
+        let mut retain = req.limits().get("tail").unwrap_or(64.kibibytes());
+        if retain > Tail::MAX {
+            warn_!("Tail limit ({}) exceeds maximum ({}). Using maximum.", retain, Tail::MAX);
+            retain = Tail::MAX;
+        }
+
+        let retain = retain.as_u64() as usize;
+        let mut ring = VecDeque::with_capacity(retain);
+        let (mut total, mut chunk) = (0, vec![0; 8 * 1024]);
+        let mut stream = data.open(u64::MAX.bytes());
+        loop {
+            let n = match stream.read(&mut chunk).await {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) => {
+                    error_!("Failed to read request body: {:?}.", e);
+                    return Failure((Status::InternalServerError, e));
+                }
+            };
+
+            // Keep at most the last `retain` bytes of the chunk, then make room.
+            total += n as u64;
+            let bytes = &chunk[n.saturating_sub(retain)..n];
+            let overflow = (ring.len() + bytes.len()).saturating_sub(retain);
+            ring.drain(..overflow);
+            ring.extend(bytes);
+        }
+
+        Success(Tail { bytes: ring.into_iter().collect(), total })
+    }
+}
@@ -0,0 +1,372 @@
+use std::io;
+use std::fmt;
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::outcome::Outcome::*;
+use crate::request::Request;
+use crate::data::{Data, Transform, Transformed, TransformFuture, ToByteUnit};
+use crate::data::{FromTransformedData, FromDataFuture};
+use crate::http::Status;
+
+/// The location a [`Tee`] archives request bodies to.
+///
+/// Implement this trait on a marker type to choose the path of the archive
+/// for a given request. [`TempArchive`], the default, chooses a unique path in
+/// the system's temporary directory.
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::PathBuf;
+/// use std::time::SystemTime;
+///
+/// use rocket::request::Request;
+/// use rocket::data::Archive;
+///
+/// struct Uploads;
+///
+/// impl Archive for Uploads {
+///     fn path(_: &Request<'_>) -> PathBuf {
+///         let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap();
+///         PathBuf::from("/var/uploads").join(format!("{}.bin", now.as_nanos()))
+///     }
+/// }
+/// ```
+pub trait Archive {
+    /// Returns the path of the file to archive the body of `request` to. Any
+    /// existing file at the path is truncated.
+    fn path(request: &Request<'_>) -> PathBuf;
+}
+
+/// An [`Archive`] that writes to a uniquely named file in the directory
+/// returned by [`std::env::temp_dir()`].
+#[derive(Debug)]
+pub struct TempArchive;
+
+impl Archive for TempArchive {
+    fn path(_: &Request<'_>) -> PathBuf {
+        std::env::temp_dir().join(format!("rocket-tee-{:016x}", rand::random::<u64>()))
+    }
+}
+
+/// A data guard that archives the raw request body to a file while the data
+/// guard `T` parses it.
+///
+/// `Tee<T, A>` wraps any data guard `T`. Every byte that `T` reads from the
+/// body is also written to the file at the path chosen by the [`Archive`] `A`,
+/// which defaults to [`TempArchive`]. The body is read only once. If `T`
+/// succeeds, the `Tee` derefs to the parsed value, and the path of the archive
+/// is available via [`Tee::path()`]. If `T` fails or forwards, the archive is
+/// removed and the outcome of `T` is returned.
+///
+/// Only data read by `T` while it runs is archived: a guard that doesn't read
+/// the entire body archives only what it reads. A body can only be archived by
+/// one `Tee`: if `T` is itself a `Tee`, the inner `Tee` fails with
+/// [`TeeError::Nested`] and the outer archive is removed.
+///
+/// # Limits
+///
+/// The archive is limited by the `tee` limit, which defaults to 1MiB. If `T`
+/// reads more than the limit, reads fail, the archive is removed, and a
+/// `Failure` with status `PayloadTooLarge` is returned.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::Form;
+/// use rocket::data::Tee;
+///
+/// #[derive(FromForm)]
+/// struct Order {
+///     item: String,
+/// }
+///
+/// #[post("/order", data = "<order>")]
+/// fn order(order: Tee<Form<Order>>) -> String {
+///     format!("{} (archived to {})", order.item, order.path().display())
+/// }
+/// # fn main() { }
+/// ```
+pub struct Tee<T, A = TempArchive> {
+    value: T,
+    path: PathBuf,
+    size: u64,
+    _archive: PhantomData<fn() -> A>,
+}
+
+/// Error returned by the data guard implementation of [`Tee`].
+#[derive(Debug)]
+pub enum TeeError<E> {
+    /// Creating or writing to the archive failed.
+    Io(io::Error),
+    /// The body read by the inner data guard exceeded the `tee` limit.
+    TooLarge,
+    /// The body is already being archived: the inner data guard is, or
+    /// contains, another `Tee`. A body can only be archived by one `Tee`.
+    Nested,
+    /// The inner data guard failed.
+    Inner(E),
+}
+
+impl<T, A> Tee<T, A> {
+    /// Consumes `self` and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the path of the archived request body.
+    #[inline(always)]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the size, in bytes, of the archived request body.
+    #[inline(always)]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+impl<T: fmt::Debug, A> fmt::Debug for Tee<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("value", &self.value)
+            .field("path", &self.path)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<T, A> Deref for Tee<T, A> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, A> DerefMut for Tee<T, A> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// The receiving end of a `DataStream` that is being archived.
+pub(crate) struct TeeSink {
+    sender: Option<mpsc::UnboundedSender<Vec<u8>>>,
+    written: u64,
+    limit: u64,
+    exceeded: bool,
+}
+
+impl TeeSink {
+    /// Queues `bytes` to be written to the archive. Returns an error if doing
+    /// so would exceed the limit. Does nothing once the sink is detached.
+    pub(crate) fn send(&mut self, bytes: &[u8]) -> io::Result<()> {
+        if self.sender.is_none() || bytes.is_empty() {
+            return Ok(());
+        }
+
+        self.written += bytes.len() as u64;
+        if self.written > self.limit {
+            self.exceeded = true;
+            self.sender = None;
+            return Err(io::Error::new(io::ErrorKind::Other, "tee limit exceeded"));
+        }
+
+        if let Some(sender) = &self.sender {
+            // If the writer failed, the error is reported when it's joined.
+            let _ = sender.send(bytes.to_vec());
+        }
+
+        Ok(())
+    }
+}
+
+/// A `TeeSink` shared between a `DataStream` and its `Tee`.
+pub(crate) type SharedTee = Arc<Mutex<TeeSink>>;
+
+/// An archive in progress, between `transform()` and `from_data()`.
+struct PendingTee {
+    sink: SharedTee,
+    writer: JoinHandle<io::Result<u64>>,
+    path: PathBuf,
+}
+
+/// The transformed data of a [`Tee`]: the inner guard's transformed value and
+/// the archive written while it was transformed. The archive is taken by the
+/// `Tee`'s `from_data()`, even when the value is borrowed.
+#[doc(hidden)]
+pub struct TeeTransformed<O> {
+    pending: Mutex<Option<PendingTee>>,
+    value: O,
+}
+
+impl PendingTee {
+    /// Detaches the sink from the data stream and waits for the writer to
+    /// finish, returning the number of bytes archived.
+    async fn finish<E>(self) -> Result<u64, (Status, TeeError<E>)> {
+        let exceeded = {
+            let mut sink = self.sink.lock().expect("tee sink lock");
+            sink.sender = None;
+            sink.exceeded
+        };
+
+        let written = self.writer.await
+            .unwrap_or_else(|e| Err(io::Error::new(io::ErrorKind::Other, e)));
+
+        if exceeded {
+            error_!("Archived data exceeded the tee limit.");
+            return Err((Status::PayloadTooLarge, TeeError::TooLarge));
+        }
+
+        written.map_err(|e| {
+            error_!("Failed to write archive: {:?}.", e);
+            (Status::InternalServerError, TeeError::Io(e))
+        })
+    }
+
+    /// Finishes and removes the archive. Returns the error to report in place
+    /// of the inner guard's outcome, if any.
+    async fn discard<E>(self) -> Option<(Status, TeeError<E>)> {
+        let path = self.path.clone();
+        let result = self.finish().await;
+        let _ = tokio::fs::remove_file(&path).await;
+        match result {
+            Err(e @ (_, TeeError::TooLarge)) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+async fn write_archive(
+    mut file: File,
+    mut chunks: mpsc::UnboundedReceiver<Vec<u8>>
+) -> io::Result<u64> {
+    let mut written = 0;
+    while let Some(chunk) = chunks.recv().await {
+        file.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+
+    file.flush().await?;
+    Ok(written)
+}
+
+/// Archives the data read by `T` while returning the outcome of `T`'s
+/// `FromTransformedData` implementation.
+impl<'r, T, A> FromTransformedData<'r> for Tee<T, A>
+    where T: FromTransformedData<'r> + Send + 'r,
+          T::Owned: Send + Sync + 'r,
+          A: Archive + 'r
+{
+    type Error = TeeError<T::Error>;
+    type Owned = TeeTransformed<T::Owned>;
+    type Borrowed = TeeTransformed<T::Owned>;
+
+    fn transform(r: &'r Request<'_>, mut d: Data) -> TransformFuture<'r, Self::Owned, Self::Error> {
+        Box::pin(async move {
+            let limit = r.limits().get("tee").unwrap_or(1.mebibytes());
+            let (sender, chunks) = mpsc::unbounded_channel();
+            let sink = Arc::new(Mutex::new(TeeSink {
+                sender: Some(sender),
+                written: 0,
+                limit: limit.as_u64(),
+                exceeded: false,
+            }));
+
+            if !d.tee(sink.clone()) {
+                error_!("Request body is already being archived by another `Tee`.");
+                return Transform::Owned(Failure((Status::InternalServerError, TeeError::Nested)));
+            }
+
+            let path = A::path(r);
+            let file = match File::create(&path).await {
+                Ok(file) => file,
+                Err(e) => {
+                    error_!("Failed to create archive {:?}: {:?}.", path, e);
+                    let err = (Status::InternalServerError, TeeError::Io(e));
+                    return Transform::Owned(Failure(err));
+                }
+            };
+
+            let writer = tokio::spawn(write_archive(file, chunks));
+            let pending = PendingTee { sink, writer, path };
+            let inner = match T::transform(r, d).await {
+                Transform::Owned(Success(v)) => Transform::Owned(v),
+                Transform::Borrowed(Success(v)) => Transform::Borrowed(v),
+                Transform::Owned(Forward(d)) | Transform::Borrowed(Forward(d)) => {
+                    return match pending.discard().await {
+                        Some(e) => Transform::Owned(Failure(e)),
+                        None => Transform::Owned(Forward(d)),
+                    };
+                }
+                Transform::Owned(Failure(e)) | Transform::Borrowed(Failure(e)) => {
+                    let (status, e) = e;
+                    let e = pending.discard().await.unwrap_or((status, TeeError::Inner(e)));
+                    return Transform::Owned(Failure(e));
+                }
+            };
+
+            let pending = Mutex::new(Some(pending));
+            match inner {
+                Transform::Owned(value) => {
+                    Transform::Owned(Success(TeeTransformed { pending, value }))
+                }
+                Transform::Borrowed(value) => {
+                    Transform::Borrowed(Success(TeeTransformed { pending, value }))
+                }
+            }
+        })
+    }
+
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+        Box::pin(async move {
+            let (pending, inner) = match o {
+                Transform::Owned(Success(v)) => {
+                    let pending = v.pending.into_inner().expect("tee pending lock");
+                    (pending, Transform::Owned(Success(v.value)))
+                }
+                Transform::Borrowed(Success(v)) => {
+                    let pending = v.pending.lock().expect("tee pending lock").take();
+                    (pending, Transform::Borrowed(Success(v.value.borrow())))
+                }
+                Transform::Owned(Forward(d)) | Transform::Borrowed(Forward(d)) => return Forward(d),
+                Transform::Owned(Failure(e)) | Transform::Borrowed(Failure(e)) => return Failure(e),
+            };
+
+            // The archive is taken at most once, by the first `from_data()`.
+            let pending = match pending {
+                Some(pending) => pending,
+                None => return Failure((Status::InternalServerError, TeeError::Nested)),
+            };
+
+            let outcome = T::from_data(r, inner).await
+                .map_failure(|(status, e)| (status, TeeError::Inner(e)));
+
+            let path = pending.path.clone();
+            let outcome = match (outcome, pending.finish().await) {
+                (_, Err(e @ (_, TeeError::TooLarge))) => Failure(e),
+                (Success(value), Ok(size)) => {
+                    return Success(Tee { value, path, size, _archive: PhantomData });
+                }
+                (Success(_), Err(e)) | (Failure(e), _) => Failure(e),
+                (Forward(d), _) => Forward(d),
+            };
+
+            let _ = tokio::fs::remove_file(&path).await;
+            outcome
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use std::{fmt, io};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use futures::future::{ready, FutureExt};
+
+use crate::outcome::Outcome::*;
+use crate::request::{Request, FromRequest, ContentLength, ContentLengthError};
+use crate::data::{ByteUnit, Data, LimitExceeded, Transform, Transformed, TransformFuture};
+use crate::data::{FromTransformedData, FromDataFuture, RequiredContentType};
+use crate::data::require_content_type::matches as content_type_matches;
+use crate::http::Status;
+
+/// The content type and limit of a [`Typed`] data guard.
+///
+/// Implement this trait, along with [`RequiredContentType`], on a marker type
+/// to declare the `Content-Type` a `Typed` guard accepts and the name of the
+/// [limit](crate::data::Limits) that bounds the body. Only the top-level and
+/// sub-level types are compared; parameters, like `charset`, are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::http::ContentType;
+/// use rocket::data::{DataSpec, RequiredContentType, ByteUnit};
+///
+/// struct Csv;
+///
+/// impl RequiredContentType for Csv {
+///     fn content_type() -> ContentType {
+///         ContentType::new("text", "csv")
+///     }
+/// }
+///
+/// impl DataSpec for Csv {
+///     const LIMIT: &'static str = "csv";
+///     const DEFAULT_LIMIT: ByteUnit = ByteUnit::Mebibyte(2);
+/// }
+/// ```
+pub trait DataSpec: RequiredContentType {
+    /// The name of the limit that bounds the body.
+    const LIMIT: &'static str;
+
+    /// The limit applied when no limit named [`DataSpec::LIMIT`] is
+    /// configured.
+    const DEFAULT_LIMIT: ByteUnit;
+}
+
+/// A data guard that checks the content type and limit declared by a
+/// [`DataSpec`] before delegating to another data guard.
+///
+/// Custom data guards commonly begin by checking the request's `Content-Type`
+/// and looking up a named limit. `Typed<S, T>` performs both steps for any data
+/// guard `T`, as declared by `S`:
+///
+///   1. If the request's content type doesn't match `S::content_type()`, or the
+///      request has none, the request is _forwarded_ so that other routes may
+///      handle it. `T` is not invoked.
+///   2. If the request's `Content-Length` is malformed, the guard _fails_ with
+///      status `BadRequest` (400) and a [`TypedError::Length`], as the
+///      [`ContentLength`] request guard does.
+///   3. If the request's `Content-Length` exceeds the limit named `S::LIMIT`,
+///      or `S::DEFAULT_LIMIT` if there is none, the guard _fails_ with status
+///      `PayloadTooLarge` (413) and a [`TypedError::TooLarge`]. The body is not
+///      read.
+///   4. Otherwise, the body is read into memory. If it exceeds the limit, which
+///      may happen when the request has no `Content-Length`, the guard fails in
+///      the same way. The body is never truncated.
+///   5. Finally, `T` is invoked with the body, and its outcome is returned with
+///      any error wrapped in a [`TypedError::Inner`]. If `T` forwards, the
+///      forwarded data contains the entire body.
+///
+/// [`ContentLength`]: crate::request::ContentLength
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::ContentType;
+/// use rocket::data::{Typed, DataSpec, RequiredContentType, ByteUnit};
+///
+/// struct Csv;
+///
+/// impl RequiredContentType for Csv {
+///     fn content_type() -> ContentType {
+///         ContentType::new("text", "csv")
+///     }
+/// }
+///
+/// impl DataSpec for Csv {
+///     const LIMIT: &'static str = "csv";
+///     const DEFAULT_LIMIT: ByteUnit = ByteUnit::Mebibyte(2);
+/// }
+///
+/// #[post("/import", data = "<csv>")]
+/// fn import(csv: Typed<Csv, String>) -> String {
+///     format!("imported {} rows", csv.lines().count())
+/// }
+/// # fn main() { }
+/// ```
+pub struct Typed<S, T> {
+    value: T,
+    _spec: PhantomData<fn() -> S>,
+}
+
+/// Error returned by the data guard implementation of [`Typed`].
+#[derive(Debug)]
+pub enum TypedError<E> {
+    /// The request's `Content-Length` header is malformed.
+    Length(ContentLengthError),
+    /// The request body exceeds the limit (in `.1`). `.0` is the request's
+    /// `Content-Length`, in which case the body was not read, or, if the
+    /// request has none, the number of bytes read before the limit was
+    /// exceeded: one more than the limit.
+    TooLarge(u64, ByteUnit),
+    /// An I/O error occurred while reading the body.
+    Io(io::Error),
+    /// The inner data guard failed.
+    Inner(E),
+}
+
+impl<S, T> Typed<S, T> {
+    /// Consumes `self` and returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<S, T: fmt::Debug> fmt::Debug for Typed<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Typed").field(&self.value).finish()
+    }
+}
+
+impl<S, T> Deref for Typed<S, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<S, T> DerefMut for Typed<S, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TypedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypedError::Length(e) => e.fmt(f),
+            TypedError::TooLarge(length, limit) => {
+                write!(f, "body length ({}) exceeds limit ({})", length, limit)
+            }
+            TypedError::Io(e) => e.fmt(f),
+            TypedError::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Forwards if the request's content type doesn't match `S`'s and fails with
+/// `413` if its body exceeds `S`'s limit. Otherwise, returns the outcome of
+/// `T`'s `FromTransformedData` implementation.
+impl<'r, S, T> FromTransformedData<'r> for Typed<S, T>
+    where S: DataSpec + 'r, T: FromTransformedData<'r> + 'r
+{
+    type Error = TypedError<T::Error>;
+    type Owned = T::Owned;
+    type Borrowed = T::Borrowed;
+
+    fn transform(r: &'r Request<'_>, d: Data) -> TransformFuture<'r, Self::Owned, Self::Error> {
+        Box::pin(async move {
+            if !content_type_matches::<S>(r) {
+                warn_!("Request body does not have Content-Type `{}`.", S::content_type());
+                return Transform::Owned(Forward(d));
+            }
+
+            let length = match ContentLength::from_request(r).await {
+                Success(ContentLength(length)) => length,
+                Failure((status, e)) => {
+                    return Transform::Owned(Failure((status, TypedError::Length(e))));
+                }
+                Forward(()) => None,
+            };
+
+            let limit = r.limits().get(S::LIMIT).unwrap_or(S::DEFAULT_LIMIT);
+            if let Some(length) = length.filter(|&len| len > limit.as_u64()) {
+                error_!("Content-Length ({}) exceeds `{}` limit ({}).", length, S::LIMIT, limit);
+                let failure = (Status::PayloadTooLarge, TypedError::TooLarge(length, limit));
+                return Transform::Owned(Failure(failure));
+            }
+
+            let body = match d.open_within(limit).stream_to_vec().await {
+                Ok(body) => body,
+                Err(e) if LimitExceeded::of(&e).is_some() => {
+                    error_!("Request body exceeds `{}` limit ({}).", S::LIMIT, limit);
+                    let length = limit.as_u64().saturating_add(1);
+                    let failure = (Status::PayloadTooLarge, TypedError::TooLarge(length, limit));
+                    return Transform::Owned(Failure(failure));
+                }
+                Err(e) => {
+                    let failure = (Status::InternalServerError, TypedError::Io(e));
+                    return Transform::Owned(Failure(failure));
+                }
+            };
+
+            let inner = |(status, e)| (status, TypedError::Inner(e));
+            match T::transform(r, Data::local(body)).await {
+                Transform::Owned(outcome) => Transform::Owned(outcome.map_failure(inner)),
+                Transform::Borrowed(outcome) => Transform::Borrowed(outcome.map_failure(inner)),
+            }
+        })
+    }
+
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+        let inner = |(status, e)| (status, TypedError::Inner(e));
+        let o = match o {
+            Transform::Owned(Failure(e)) | Transform::Borrowed(Failure(e)) => {
+                return Box::pin(ready(Failure(e)));
+            }
+            Transform::Owned(Forward(d)) | Transform::Borrowed(Forward(d)) => {
+                return Box::pin(ready(Forward(d)));
+            }
+            Transform::Owned(Success(v)) => Transform::Owned(Success(v)),
+            Transform::Borrowed(Success(v)) => Transform::Borrowed(Success(v)),
+        };
+
+        Box::pin(T::from_data(r, o).map(move |outcome| {
+            outcome.map(|value| Typed { value, _spec: PhantomData })
+                .map_failure(inner)
+        }))
+    }
+}
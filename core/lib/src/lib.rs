@@ -141,7 +141,7 @@ mod ext;
 
 #[doc(hidden)] pub use log::{info, warn, error, debug};
 #[doc(inline)] pub use crate::response::Response;
-#[doc(hidden)] pub use crate::codegen::{StaticRouteInfo, StaticCatcherInfo};
+#[doc(hidden)] pub use crate::codegen::{StaticRouteInfo, StaticCatcherInfo, Lazy};
 #[doc(inline)] pub use crate::data::Data;
 #[doc(inline)] pub use crate::config::Config;
 #[doc(inline)] pub use crate::catcher::Catcher;
@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+
+use crate::request::{self, FromRequest, Request};
+use crate::outcome::Outcome::*;
+
+/// Request guard for the content-codings a client accepts in a response.
+///
+/// `AcceptsEncoding` parses every `Accept-Encoding` header in the request into
+/// a list of content-codings ordered by quality value, from most to least
+/// preferred. Handlers that compress their own output can use it to decide
+/// whether, and how, to do so.
+///
+/// Codings are matched case-insensitively. A coding's quality is the quality
+/// of its own entry or, if it isn't listed, that of the `*` entry, if any. The
+/// `identity` coding is acceptable unless it is explicitly rejected, either
+/// with `identity;q=0` or with `*;q=0` when `identity` isn't listed. When the
+/// request has no `Accept-Encoding` header, only `identity` is acceptable.
+///
+/// _This guard always succeeds._
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::AcceptsEncoding;
+///
+/// #[get("/")]
+/// fn index(encodings: AcceptsEncoding) -> &'static str {
+///     match encodings.preferred(&["br", "gzip", "identity"]) {
+///         Some("br") => "brotli",
+///         Some("gzip") => "gzip",
+///         Some(_) => "uncompressed",
+///         None => "nothing acceptable",
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AcceptsEncoding {
+    /// The listed codings, lowercased, sorted by descending quality.
+    codings: Vec<(String, f32)>,
+}
+
+impl AcceptsEncoding {
+    /// Parses the values of `Accept-Encoding` headers. Entries with malformed
+    /// quality values are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::AcceptsEncoding;
+    ///
+    /// let encodings = AcceptsEncoding::parse(vec!["gzip;q=0.5, br"]);
+    /// assert_eq!(encodings.quality("br"), 1.0);
+    /// assert_eq!(encodings.quality("gzip"), 0.5);
+    /// ```
+    pub fn parse<'a, I: IntoIterator<Item = &'a str>>(values: I) -> AcceptsEncoding {
+        let mut codings = vec![];
+        for item in values.into_iter().flat_map(|value| value.split(',')) {
+            let mut params = item.split(';');
+            let coding = params.next().unwrap_or("").trim();
+            if coding.is_empty() {
+                continue;
+            }
+
+            let quality = params.filter_map(|p| p.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().ok().filter(|q| *q >= 0.0 && *q <= 1.0))
+                .last();
+
+            match quality {
+                Some(Some(q)) => codings.push((coding.to_ascii_lowercase(), q)),
+                Some(None) => continue,
+                None => codings.push((coding.to_ascii_lowercase(), 1.0)),
+            }
+        }
+
+        // A stable sort keeps the client's order for codings of equal quality.
+        codings.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("q-values are finite"));
+        AcceptsEncoding { codings }
+    }
+
+    /// Returns the quality value of `coding`, between `0.0` and `1.0`. A value
+    /// of `0.0` means the coding is not acceptable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::AcceptsEncoding;
+    ///
+    /// let encodings = AcceptsEncoding::parse(vec!["gzip, *;q=0.2"]);
+    /// assert_eq!(encodings.quality("GZIP"), 1.0);
+    /// assert_eq!(encodings.quality("br"), 0.2);
+    /// assert_eq!(encodings.quality("identity"), 0.2);
+    /// ```
+    pub fn quality(&self, coding: &str) -> f32 {
+        let find = |name: &str| self.codings.iter()
+            .find(|(c, _)| c.eq_ignore_ascii_case(name))
+            .map(|(_, q)| *q);
+
+        find(coding)
+            .or_else(|| find("*"))
+            .unwrap_or(if coding.eq_ignore_ascii_case("identity") { 1.0 } else { 0.0 })
+    }
+
+    /// Returns `true` if `coding` is acceptable to the client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::AcceptsEncoding;
+    ///
+    /// let encodings = AcceptsEncoding::parse(vec!["gzip", "identity;q=0"]);
+    /// assert!(encodings.accepts("gzip"));
+    /// assert!(!encodings.accepts("br"));
+    /// assert!(!encodings.accepts("identity"));
+    /// ```
+    pub fn accepts(&self, coding: &str) -> bool {
+        self.quality(coding) > 0.0
+    }
+
+    /// Returns the acceptable coding in `available` with the highest quality
+    /// value, or `None` if none are acceptable. Ties are broken in favor of
+    /// codings that appear earlier in `available`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::AcceptsEncoding;
+    ///
+    /// let encodings = AcceptsEncoding::parse(vec!["gzip;q=0.8, br;q=0.8, deflate"]);
+    /// assert_eq!(encodings.preferred(&["br", "gzip"]), Some("br"));
+    /// assert_eq!(encodings.preferred(&["gzip", "deflate"]), Some("deflate"));
+    /// assert_eq!(encodings.preferred(&["zstd"]), None);
+    /// ```
+    pub fn preferred<'c>(&self, available: &[&'c str]) -> Option<&'c str> {
+        let mut best: Option<(&'c str, f32)> = None;
+        for &coding in available {
+            let quality = self.quality(coding);
+            if quality > best.map_or(0.0, |(_, q)| q) {
+                best = Some((coding, quality));
+            }
+        }
+
+        best.map(|(coding, _)| coding)
+    }
+
+    /// Returns an iterator over the listed, acceptable codings and their
+    /// quality values, from most to least preferred. The iterator may yield
+    /// `*`. Codings are lowercased.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::AcceptsEncoding;
+    ///
+    /// let encodings = AcceptsEncoding::parse(vec!["gzip;q=0.5, br, identity;q=0"]);
+    /// let codings: Vec<_> = encodings.iter().collect();
+    /// assert_eq!(codings, vec![("br", 1.0), ("gzip", 0.5)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f32)> + '_ {
+        self.codings.iter()
+            .filter(|(_, q)| *q > 0.0)
+            .map(|(coding, q)| (coding.as_str(), *q))
+    }
+}
+
+#[crate::async_trait]
+impl<'a, 'r> FromRequest<'a, 'r> for AcceptsEncoding {
+    type Error = Infallible;
+
+    async fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, Infallible> {
+        Success(AcceptsEncoding::parse(req.headers().get("Accept-Encoding")))
+    }
+}
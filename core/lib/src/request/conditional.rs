@@ -0,0 +1,351 @@
+use std::fmt;
+use std::convert::Infallible;
+
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::request::{self, FromRequest, Request};
+use crate::outcome::Outcome::*;
+use crate::http::{Method, Status};
+
+/// An entity tag, as used in the `ETag`, `If-Match`, and `If-None-Match`
+/// headers.
+///
+/// The `Display` implementation of `ETag` formats the tag as it appears in
+/// headers: `"tag"` for strong tags and `W/"tag"` for weak tags.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::ETag;
+///
+/// assert_eq!(ETag::strong("v2").to_string(), "\"v2\"");
+/// assert_eq!(ETag::weak("v2").to_string(), "W/\"v2\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ETag {
+    tag: String,
+    weak: bool,
+}
+
+impl ETag {
+    /// Creates a strong entity tag with the opaque tag `tag`. `tag` should not
+    /// contain `"`.
+    pub fn strong<S: Into<String>>(tag: S) -> ETag {
+        ETag { tag: tag.into(), weak: false }
+    }
+
+    /// Creates a weak entity tag with the opaque tag `tag`. `tag` should not
+    /// contain `"`.
+    pub fn weak<S: Into<String>>(tag: S) -> ETag {
+        ETag { tag: tag.into(), weak: true }
+    }
+
+    /// Returns the opaque tag, without quotes or weakness indicator.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns `true` if this is a weak entity tag.
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    /// Returns `true` if `self` and `other` are both strong and have the same
+    /// opaque tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ETag;
+    ///
+    /// assert!(ETag::strong("a").strong_eq(&ETag::strong("a")));
+    /// assert!(!ETag::strong("a").strong_eq(&ETag::weak("a")));
+    /// ```
+    pub fn strong_eq(&self, other: &ETag) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    /// Returns `true` if `self` and `other` have the same opaque tag,
+    /// regardless of their weakness.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ETag;
+    ///
+    /// assert!(ETag::strong("a").weak_eq(&ETag::weak("a")));
+    /// assert!(!ETag::weak("a").weak_eq(&ETag::weak("b")));
+    /// ```
+    pub fn weak_eq(&self, other: &ETag) -> bool {
+        self.tag == other.tag
+    }
+
+    /// Parses a single entity tag. Returns `None` if `string` isn't one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ETag;
+    ///
+    /// assert_eq!(ETag::parse("\"v2\""), Some(ETag::strong("v2")));
+    /// assert_eq!(ETag::parse("W/\"v2\""), Some(ETag::weak("v2")));
+    /// assert_eq!(ETag::parse("v2"), None);
+    /// ```
+    pub fn parse(string: &str) -> Option<ETag> {
+        match parse_etags(string).as_slice() {
+            [Some(etag)] => Some(etag.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ETag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weak {
+            write!(f, "W/")?;
+        }
+
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+/// The value of an `If-Match` or `If-None-Match` precondition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityTags {
+    /// `*`: any current representation of the resource.
+    Any,
+    /// A list of entity tags. Malformed entries are dropped.
+    Tags(Vec<ETag>),
+}
+
+impl EntityTags {
+    /// Parses the values of `If-Match` or `If-None-Match` headers.
+    fn parse<'a, I: IntoIterator<Item = &'a str>>(values: I) -> Option<EntityTags> {
+        let mut tags = vec![];
+        let mut present = false;
+        for value in values {
+            present = true;
+            if value.trim() == "*" {
+                return Some(EntityTags::Any);
+            }
+
+            tags.extend(parse_etags(value).into_iter().flatten());
+        }
+
+        match present {
+            true => Some(EntityTags::Tags(tags)),
+            false => None,
+        }
+    }
+
+    /// Returns `true` if the representation with entity tag `current`, or no
+    /// representation if `current` is `None`, matches, comparing tags with
+    /// `eq`.
+    fn matches(&self, current: Option<&ETag>, eq: fn(&ETag, &ETag) -> bool) -> bool {
+        match (self, current) {
+            (_, None) => false,
+            (EntityTags::Any, Some(_)) => true,
+            (EntityTags::Tags(tags), Some(current)) => tags.iter().any(|t| eq(t, current)),
+        }
+    }
+}
+
+/// Parses a comma-separated list of entity tags. Each element is `None` if the
+/// corresponding entry is malformed. Entity tags may themselves contain commas,
+/// so the list can't simply be split.
+fn parse_etags(string: &str) -> Vec<Option<ETag>> {
+    let mut etags = vec![];
+    let mut rest = string.trim_start();
+    while !rest.is_empty() {
+        let (weak, quoted) = match rest.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, rest),
+        };
+
+        let etag = quoted.strip_prefix('"')
+            .and_then(|tag| tag.find('"').map(|end| (&tag[..end], tag[end + 1..].trim_start())))
+            .filter(|(_, after)| after.is_empty() || after.starts_with(','));
+
+        rest = match etag {
+            Some((tag, after)) => {
+                etags.push(Some(ETag { tag: tag.to_string(), weak }));
+                after
+            }
+            None => {
+                etags.push(None);
+                rest.find(',').map_or("", |i| &rest[i..])
+            }
+        };
+
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+    }
+
+    etags
+}
+
+/// Parses an HTTP-date in the preferred IMF-fixdate format, as in `Sun, 06 Nov
+/// 1994 08:49:37 GMT`.
+fn parse_http_date(string: &str) -> Option<OffsetDateTime> {
+    PrimitiveDateTime::parse(string.trim(), "%a, %d %b %Y %H:%M:%S GMT")
+        .ok()
+        .map(|date| date.assume_utc())
+}
+
+/// Request guard for the conditional request headers of RFC 7232.
+///
+/// `Conditional` parses the `If-Match`, `If-None-Match`, `If-Modified-Since`,
+/// and `If-Unmodified-Since` headers of a request. Handlers evaluate the
+/// preconditions against the current state of the resource with
+/// [`Conditional::evaluate()`], which returns the status to respond with when a
+/// precondition fails: `412 Precondition Failed`, or `304 Not Modified` for
+/// `GET` and `HEAD` requests whose cached representation is fresh. This makes
+/// optimistic concurrency control uniform: a `PUT` with `If-Match` only
+/// proceeds if the client has seen the current representation.
+///
+/// Dates are only accepted in the IMF-fixdate format. Malformed dates are
+/// ignored, as required by the RFC, while malformed entity tags are dropped
+/// from their list.
+///
+/// _This guard always succeeds._
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Conditional, ETag};
+/// use rocket::http::Status;
+///
+/// #[put("/doc", data = "<doc>")]
+/// fn update(conditions: Conditional, doc: String) -> Result<&'static str, Status> {
+///     let current = ETag::strong("v2");
+///     if let Some(status) = conditions.evaluate(Some(&current), None) {
+///         return Err(status);
+///     }
+///
+///     Ok("updated")
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conditional {
+    method: Method,
+    if_match: Option<EntityTags>,
+    if_none_match: Option<EntityTags>,
+    if_modified_since: Option<OffsetDateTime>,
+    if_unmodified_since: Option<OffsetDateTime>,
+}
+
+impl Conditional {
+    /// Returns the value of the `If-Match` headers, if any.
+    pub fn if_match(&self) -> Option<&EntityTags> {
+        self.if_match.as_ref()
+    }
+
+    /// Returns the value of the `If-None-Match` headers, if any.
+    pub fn if_none_match(&self) -> Option<&EntityTags> {
+        self.if_none_match.as_ref()
+    }
+
+    /// Returns the date in the `If-Modified-Since` header, if any is valid.
+    pub fn if_modified_since(&self) -> Option<OffsetDateTime> {
+        self.if_modified_since
+    }
+
+    /// Returns the date in the `If-Unmodified-Since` header, if any is valid.
+    pub fn if_unmodified_since(&self) -> Option<OffsetDateTime> {
+        self.if_unmodified_since
+    }
+
+    /// Returns `true` if the request contains any valid precondition.
+    pub fn is_conditional(&self) -> bool {
+        self.if_match.is_some() || self.if_none_match.is_some()
+            || self.if_modified_since.is_some() || self.if_unmodified_since.is_some()
+    }
+
+    /// Evaluates the preconditions against the current representation of the
+    /// resource, whose entity tag is `etag` and which was last modified at
+    /// `last_modified`. Either is `None` if unknown; `etag` is `None` if the
+    /// resource doesn't exist. Returns `None` if the request should proceed
+    /// and the status to respond with otherwise.
+    ///
+    /// Preconditions are evaluated in the order prescribed by RFC 7232,
+    /// section 6:
+    ///
+    ///   1. If `If-Match` is present and no tag matches `etag` by strong
+    ///      comparison, `412` is returned. Otherwise, if
+    ///      `If-Unmodified-Since` is present and the resource was modified
+    ///      after the date, `412` is returned.
+    ///   2. If `If-None-Match` is present and a tag matches `etag` by weak
+    ///      comparison, `304` is returned for `GET` and `HEAD` requests and
+    ///      `412` for all others. Otherwise, if `If-Modified-Since` is present,
+    ///      the request is a `GET` or `HEAD`, and the resource wasn't modified
+    ///      after the date, `304` is returned.
+    ///
+    /// Dates are compared with a precision of one second, the precision of an
+    /// HTTP-date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::request::{Conditional, ETag};
+    /// use rocket::http::Status;
+    ///
+    /// #[get("/doc")]
+    /// fn doc(conditions: Conditional) -> Result<&'static str, Status> {
+    ///     match conditions.evaluate(Some(&ETag::weak("v2")), None) {
+    ///         Some(status) => Err(status),
+    ///         None => Ok("the document"),
+    ///     }
+    /// }
+    /// ```
+    pub fn evaluate(
+        &self,
+        etag: Option<&ETag>,
+        last_modified: Option<OffsetDateTime>
+    ) -> Option<Status> {
+        let modified_after = |date: OffsetDateTime| last_modified
+            .map(|modified| modified.unix_timestamp() > date.unix_timestamp());
+
+        if let Some(tags) = &self.if_match {
+            if !tags.matches(etag, ETag::strong_eq) {
+                return Some(Status::PreconditionFailed);
+            }
+        } else if let Some(date) = self.if_unmodified_since {
+            if modified_after(date).unwrap_or(false) {
+                return Some(Status::PreconditionFailed);
+            }
+        }
+
+        let is_read = self.method == Method::Get || self.method == Method::Head;
+        if let Some(tags) = &self.if_none_match {
+            if tags.matches(etag, ETag::weak_eq) {
+                return match is_read {
+                    true => Some(Status::NotModified),
+                    false => Some(Status::PreconditionFailed),
+                };
+            }
+        } else if let Some(date) = self.if_modified_since {
+            if is_read && modified_after(date) == Some(false) {
+                return Some(Status::NotModified);
+            }
+        }
+
+        None
+    }
+}
+
+#[crate::async_trait]
+impl<'a, 'r> FromRequest<'a, 'r> for Conditional {
+    type Error = Infallible;
+
+    async fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, Infallible> {
+        let headers = req.headers();
+        Success(Conditional {
+            method: req.method(),
+            if_match: EntityTags::parse(headers.get("If-Match")),
+            if_none_match: EntityTags::parse(headers.get("If-None-Match")),
+            if_modified_since: headers.get_one("If-Modified-Since").and_then(parse_http_date),
+            if_unmodified_since: headers.get_one("If-Unmodified-Since").and_then(parse_http_date),
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+use std::ops::Deref;
+
+use crate::request::{self, FromRequest, Request};
+use crate::outcome::Outcome::*;
+use crate::http::Status;
+
+/// Request guard for the declared length of the request body.
+///
+/// `ContentLength` is the value of the request's `Content-Length` header, or
+/// `None` if the request has none, as is the case for chunked bodies. Handlers
+/// can use it to pre-size buffers or to reject bodies that are too large before
+/// reading them. The declared length is a claim by the client; the body may be
+/// shorter, and reads of it remain subject to the configured limits.
+///
+/// # Failure
+///
+/// If the header is not an unsigned integer, or if the request contains
+/// several `Content-Length` headers with different values, the guard fails
+/// with status `BadRequest` and a [`ContentLengthError`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::Status;
+/// use rocket::request::ContentLength;
+///
+/// #[post("/upload", data = "<body>")]
+/// fn upload(length: ContentLength, body: Vec<u8>) -> Result<String, Status> {
+///     match *length {
+///         Some(length) if length > 1 << 20 => Err(Status::PayloadTooLarge),
+///         _ => Ok(format!("received {} bytes", body.len())),
+///     }
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ContentLength(pub Option<u64>);
+
+/// Error returned by the [`ContentLength`] request guard when a request's
+/// `Content-Length` header is malformed. `.0` is the offending value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentLengthError(pub String);
+
+impl Deref for ContentLength {
+    type Target = Option<u64>;
+
+    fn deref(&self) -> &Option<u64> {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContentLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Content-Length: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ContentLengthError { }
+
+#[crate::async_trait]
+impl<'a, 'r> FromRequest<'a, 'r> for ContentLength {
+    type Error = ContentLengthError;
+
+    async fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let mut length = None;
+        for value in req.headers().get("Content-Length") {
+            let error = || ContentLengthError(value.to_string());
+            let parsed = match value.trim().parse::<u64>() {
+                Ok(parsed) if length.map_or(true, |length| length == parsed) => parsed,
+                _ => {
+                    error_!("Request has {}.", error());
+                    return Failure((Status::BadRequest, error()));
+                }
+            };
+
+            length = Some(parsed);
+        }
+
+        Success(ContentLength(length))
+    }
+}
@@ -0,0 +1,95 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::http::RawStr;
+use crate::request::FromFormValue;
+
+/// An optional form value that is `None` when the submitted value is empty.
+///
+/// An `Option<T>` form field is `None` only when the field is missing or its
+/// value fails to parse as a `T`. Because an empty value parses successfully as
+/// a `String`, a text input submitted without any text, as in `name=`, yields
+/// `Some("")`. `EmptyAsNone<T>` instead yields `None` for an empty value, so
+/// that an empty input and a missing field are treated alike, as most web
+/// forms expect. Non-empty values are parsed as a `T`; unlike with `Option<T>`,
+/// a value that fails to parse is an error.
+///
+/// Only a value that is empty as submitted is treated as `None`: a value
+/// consisting of encoded whitespace, such as `+`, is parsed as a `T`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Form, EmptyAsNone};
+///
+/// #[derive(FromForm)]
+/// struct Profile {
+///     name: String,
+///     nickname: EmptyAsNone<String>,
+/// }
+///
+/// #[post("/profile", data = "<profile>")]
+/// fn profile(profile: Form<Profile>) -> String {
+///     match &*profile.nickname {
+///         Some(nickname) => format!("{} ({})", profile.name, nickname),
+///         None => profile.name.clone(),
+///     }
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EmptyAsNone<T>(pub Option<T>);
+
+impl<T> EmptyAsNone<T> {
+    /// Consumes `self` and returns the inner `Option`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{FromFormValue, EmptyAsNone};
+    /// use rocket::http::RawStr;
+    ///
+    /// let value = EmptyAsNone::<String>::from_form_value(RawStr::from_str("")).unwrap();
+    /// assert_eq!(value.into_inner(), None);
+    ///
+    /// let value = EmptyAsNone::<String>::from_form_value(RawStr::from_str("Bo")).unwrap();
+    /// assert_eq!(value.into_inner(), Some("Bo".to_string()));
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for EmptyAsNone<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Option<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for EmptyAsNone<T> {
+    fn deref_mut(&mut self) -> &mut Option<T> {
+        &mut self.0
+    }
+}
+
+/// Returns `None` for an empty value and otherwise parses the value as a `T`,
+/// returning `T`'s error on failure. A missing field is `None`.
+impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for EmptyAsNone<T> {
+    type Error = T::Error;
+
+    #[inline]
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        match v.is_empty() {
+            true => Ok(EmptyAsNone(None)),
+            false => T::from_form_value(v).map(|value| EmptyAsNone(Some(value))),
+        }
+    }
+
+    #[inline(always)]
+    fn default() -> Option<Self> {
+        Some(EmptyAsNone(None))
+    }
+}
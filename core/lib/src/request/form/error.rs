@@ -1,5 +1,6 @@
-use std::io;
-use crate::http::RawStr;
+use std::{fmt, io};
+use crate::http::{RawStr, ext::IntoOwned};
+use crate::request::ContentLengthError;
 
 /// Error returned by the [`FromForm`](crate::request::FromForm) derive on form
 /// parsing errors.
@@ -7,9 +8,15 @@ use crate::http::RawStr;
 /// If multiple errors occur while parsing a form, the first error in the
 /// following precedence, from highest to lowest, is returned:
 ///
+///   * `Missing` or `UnknownTag` for the tag field of a tagged enum
 ///   * `BadValue` or `Unknown` in incoming form string field order
+///   * `MissingRequired` for the fields in the structure's `require` list
 ///   * `Missing` in lexical field order
+///
+/// New variants may be added in the future; matches on this type must include
+/// a wildcard arm.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FormParseError<'f> {
     /// The field named `.0` with value `.1` failed to parse or validate.
     BadValue(&'f RawStr, &'f RawStr),
@@ -20,11 +27,271 @@ pub enum FormParseError<'f> {
     Unknown(&'f RawStr, &'f RawStr),
     /// The field named `.0` was expected but is missing in the incoming form.
     Missing(&'f RawStr),
+    /// The tag field named `.0` of a tagged enum has value `.1`, which doesn't
+    /// match any of the enum's variants. The valid values are in `.2`.
+    UnknownTag(&'f RawStr, &'f RawStr, &'static [&'static str]),
+    /// The fields in `.0`, declared as required with `#[form(require = "...")]`,
+    /// are missing in the incoming form.
+    MissingRequired(RequiredFields),
+}
+
+/// The missing fields of a [`FormParseError::MissingRequired`] error.
+///
+/// The `Display` implementation lists the missing fields in the order they
+/// were declared. So that errors remain `Copy`, the missing fields are tracked
+/// as a fixed-size list of indices into the `require` list; the derive rejects
+/// `require` lists with more than 64 fields.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Form, FormParseError};
+///
+/// #[derive(FromForm)]
+/// #[form(require = "name, email, phone")]
+/// struct Contact {
+///     name: String,
+///     email: String,
+///     phone: Option<String>,
+/// }
+///
+/// let error = Form::<Contact>::parse_pairs(vec![("email", "a@b")]).unwrap_err();
+/// assert_eq!(error.to_string(), "missing required fields: name, phone");
+/// ```
+#[derive(Copy, Clone)]
+pub struct RequiredFields {
+    names: &'static [&'static str],
+    missing: [u8; RequiredFields::MAX],
+    len: u8,
+}
+
+impl RequiredFields {
+    #[doc(hidden)]
+    pub const MAX: usize = 64;
+
+    #[doc(hidden)]
+    pub fn new(names: &'static [&'static str]) -> Self {
+        assert!(names.len() <= Self::MAX, "at most 64 fields can be required");
+        RequiredFields { names, missing: [0; Self::MAX], len: 0 }
+    }
+
+    /// Records the field at `index` in the `require` list as missing. Fields
+    /// must be recorded in declaration order.
+    #[doc(hidden)]
+    pub fn push(&mut self, index: u8) {
+        self.missing[self.len as usize] = index;
+        self.len += 1;
+    }
+
+    #[doc(hidden)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the names of the missing fields in the order they were declared.
+    pub fn missing(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let names = self.names;
+        self.indices().iter().map(move |&i| names[i as usize])
+    }
+
+    /// Returns `true` if the field named `name` is missing.
+    pub fn is_missing(&self, name: &str) -> bool {
+        self.missing().any(|missing| missing == name)
+    }
+
+    fn indices(&self) -> &[u8] {
+        &self.missing[..self.len as usize]
+    }
+}
+
+impl fmt::Debug for RequiredFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.missing()).finish()
+    }
+}
+
+impl PartialEq for RequiredFields {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names && self.indices() == other.indices()
+    }
+}
+
+impl Eq for RequiredFields { }
+
+impl std::hash::Hash for RequiredFields {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.names.hash(state);
+        self.indices().hash(state);
+    }
+}
+
+impl fmt::Display for RequiredFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.missing().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the error as a human-readable `field: message` line with the field
+/// name and value URL-decoded.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::FormParseError;
+///
+/// let error = FormParseError::BadValue("age".into(), "ten+years".into());
+/// assert_eq!(error.to_string(), "age: invalid value \"ten years\"");
+///
+/// let error = FormParseError::Missing("name".into());
+/// assert_eq!(error.to_string(), "name: missing");
+/// ```
+impl fmt::Display for FormParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormParseError::BadValue(k, v) => {
+                write!(f, "{}: invalid value {:?}", k.url_decode_lossy(), v.url_decode_lossy())
+            }
+            FormParseError::Unknown(k, v) => {
+                write!(f, "{}: unexpected field with value {:?}",
+                    k.url_decode_lossy(), v.url_decode_lossy())
+            }
+            FormParseError::Missing(k) => write!(f, "{}: missing", k.url_decode_lossy()),
+            FormParseError::UnknownTag(k, v, values) => {
+                write!(f, "{}: unknown value {:?}; expected one of: {}",
+                    k.url_decode_lossy(), v.url_decode_lossy(), values.join(", "))
+            }
+            FormParseError::MissingRequired(fields) => {
+                write!(f, "missing required fields: {}", fields)
+            }
+        }
+    }
+}
+
+impl IntoOwned for FormParseError<'_> {
+    type Owned = OwnedFormParseError;
+
+    fn into_owned(self) -> OwnedFormParseError {
+        use self::OwnedFormParseError::*;
+
+        let decode = |s: &RawStr| s.url_decode_lossy();
+        match self {
+            FormParseError::BadValue(k, v) => BadValue(decode(k), decode(v)),
+            FormParseError::Unknown(k, v) => Unknown(decode(k), decode(v)),
+            FormParseError::Missing(k) => Missing(decode(k)),
+            FormParseError::UnknownTag(k, v, values) => UnknownTag(decode(k), decode(v), values),
+            FormParseError::MissingRequired(fields) => MissingRequired(fields),
+        }
+    }
+}
+
+/// An owned [`FormParseError`], returned by
+/// [`Form::parse_pairs()`](crate::request::Form::parse_pairs()).
+///
+/// Each variant corresponds to the [`FormParseError`] variant of the same name.
+/// Field names and values are URL-decoded. The `Display` implementation
+/// matches that of `FormParseError`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Form, OwnedFormParseError};
+///
+/// #[derive(Debug, FromForm)]
+/// struct Search {
+///     query: String,
+///     page: usize,
+/// }
+///
+/// let error = Form::<Search>::parse_pairs(vec![("query", "a"), ("page", "two")]);
+/// let expected = OwnedFormParseError::BadValue("page".into(), "two".into());
+/// assert_eq!(error.unwrap_err(), expected);
+/// assert_eq!(expected.to_string(), "page: invalid value \"two\"");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OwnedFormParseError {
+    /// The field named `.0` with value `.1` failed to parse or validate.
+    BadValue(String, String),
+    /// The parse was strict and the field named `.0` with value `.1` appeared
+    /// in the incoming form but was not expected.
+    Unknown(String, String),
+    /// The field named `.0` was expected but is missing in the incoming form.
+    Missing(String),
+    /// The tag field named `.0` of a tagged enum has value `.1`, which doesn't
+    /// match any of the enum's variants. The valid values are in `.2`.
+    UnknownTag(String, String, &'static [&'static str]),
+    /// The fields in `.0`, declared as required with `#[form(require = "...")]`,
+    /// are missing in the incoming form.
+    MissingRequired(RequiredFields),
+}
+
+impl fmt::Display for OwnedFormParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OwnedFormParseError::BadValue(k, v) => write!(f, "{}: invalid value {:?}", k, v),
+            OwnedFormParseError::Unknown(k, v) => {
+                write!(f, "{}: unexpected field with value {:?}", k, v)
+            }
+            OwnedFormParseError::Missing(k) => write!(f, "{}: missing", k),
+            OwnedFormParseError::UnknownTag(k, v, values) => {
+                write!(f, "{}: unknown value {:?}; expected one of: {}", k, v, values.join(", "))
+            }
+            OwnedFormParseError::MissingRequired(fields) => {
+                write!(f, "missing required fields: {}", fields)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OwnedFormParseError { }
+
+/// Error returned by [`FromFormValue`](crate::request::FromFormValue)
+/// implementations derived for enums when a value matches none of the enum's
+/// variants.
+///
+/// The `Display` implementation lists the valid values.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::UnknownValue;
+///
+/// let error = UnknownValue { value: "fourth".into(), expected: &["first", "second"] };
+/// assert_eq!(error.to_string(), "unknown value \"fourth\"; expected one of: first, second");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnknownValue<'v> {
+    /// The raw form value that failed to match.
+    pub value: &'v RawStr,
+    /// The values that would have been accepted. Values of derived enums are
+    /// matched case-insensitively.
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for UnknownValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown value {:?}; expected one of: {}",
+            self.value.url_decode_lossy(), self.expected.join(", "))
+    }
 }
 
 /// Error returned by the [`FromTransformedData`](crate::data::FromTransformedData) implementations of
 /// [`Form`](crate::request::Form) and [`LenientForm`](crate::request::LenientForm).
+///
+/// New variants may be added in the future; matches on this type must include
+/// a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum FormDataError<'f, E> {
     /// An I/O error occurred while reading reading the data stream. This can
     /// also mean that the form contained invalid UTF-8.
@@ -32,11 +299,37 @@ pub enum FormDataError<'f, E> {
     /// The form string (in `.0`) is malformed and was unable to be parsed as
     /// HTTP `application/x-www-form-urlencoded` data.
     Malformed(&'f str),
+    /// The request's `Content-Length` header is malformed. The body was not
+    /// read.
+    Length(ContentLengthError),
+    /// The request body exceeds the applicable limit. `.0` is the request's
+    /// `Content-Length`, in which case the body was not read, or, if the
+    /// request has none, the number of bytes read before the limit was
+    /// exceeded: one more than the limit.
+    TooLarge(u64),
+    /// The request body ended after `.0` bytes, before the `.1` bytes declared
+    /// by its `Content-Length` were read. The form was not parsed.
+    Incomplete(u64, u64),
     /// The form string (in `.1`) failed to parse as the intended structure. The
     /// error type in `.0` contains further details.
     Parse(E, &'f str)
 }
 
+impl<E: fmt::Display> fmt::Display for FormDataError<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormDataError::Io(e) => write!(f, "I/O error: {}", e),
+            FormDataError::Malformed(_) => write!(f, "malformed form data"),
+            FormDataError::Length(e) => e.fmt(f),
+            FormDataError::TooLarge(n) => write!(f, "form data too large: {} bytes", n),
+            FormDataError::Incomplete(read, expected) => {
+                write!(f, "incomplete form data: read {} of {} bytes", read, expected)
+            }
+            FormDataError::Parse(e, _) => e.fmt(f),
+        }
+    }
+}
+
 /// Alias to the type of form errors returned by the [`FromTransformedData`]
 /// implementations of [`Form<T>`] where the [`FromForm`] implementation for `T`
 /// was derived.
@@ -63,9 +356,12 @@ pub enum FormDataError<'f, E> {
 ///     match sink {
 ///         Ok(form) => form.into_inner().value,
 ///         Err(FormDataError::Io(_)) => "I/O error".into(),
+///         Err(FormDataError::TooLarge(n)) => format!("{} bytes is too many", n),
+///         Err(FormDataError::Incomplete(..)) => "incomplete form; try again".into(),
 ///         Err(FormDataError::Malformed(f)) | Err(FormDataError::Parse(_, f)) => {
 ///             format!("invalid form input: {}", f)
 ///         }
+///         Err(e) => e.to_string(),
 ///     }
 /// }
 /// # fn main() {}
@@ -1,10 +1,13 @@
 use std::ops::{Deref, DerefMut};
+use std::time::Instant;
 
 use crate::outcome::Outcome::*;
-use crate::request::{Request, form::{FromForm, FormItems, FormDataError}};
-use crate::data::{Data, Outcome, Transform, Transformed, ToByteUnit};
+use crate::request::{Request, FromRequest, ContentLength};
+use crate::request::form::{FromForm, FormItems, FormItem, FormDataError};
+use crate::request::form::{FormMetrics, FormMetricsSink};
+use crate::data::{Data, Outcome, Transform, Transformed, ToByteUnit, LimitExceeded};
 use crate::data::{TransformFuture, FromTransformedData, FromDataFuture};
-use crate::http::{Status, uri::{Query, FromUriParam}};
+use crate::http::{Status, ext::IntoOwned, uri::{Query, FromUriParam}};
 
 /// A data guard for parsing [`FromForm`] types strictly.
 ///
@@ -136,6 +139,63 @@ impl<T> Form<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Strictly parses a `T` from `(key, value)` pairs with URL-decoded
+    /// values, such as pairs obtained from a source other than an HTTP form.
+    ///
+    /// Each pair becomes one form item, so values may contain any character,
+    /// including `&` and `=`, and are not decoded a second time. Keys are used
+    /// as-is; a derived [`FromForm`] implementation matches them against field
+    /// names without decoding them. Pairs with an empty key _and_ an empty
+    /// value are skipped, as they are when parsing a form string. If parsing
+    /// fails, the owned version of the [`FromForm::Error`] is returned. For
+    /// derived implementations, this is an [`OwnedFormParseError`].
+    ///
+    /// [`OwnedFormParseError`]: crate::request::OwnedFormParseError
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::request::{Form, OwnedFormParseError};
+    ///
+    /// #[derive(Debug, PartialEq, FromForm)]
+    /// struct Search {
+    ///     query: String,
+    ///     page: usize,
+    /// }
+    ///
+    /// let pairs = vec![("query", "fish & chips = 100%"), ("page", "2")];
+    /// let search = Form::<Search>::parse_pairs(pairs).unwrap();
+    /// assert_eq!(search.query, "fish & chips = 100%");
+    /// assert_eq!(search.page, 2);
+    ///
+    /// let error = Form::<Search>::parse_pairs(vec![("query", "fish")]).unwrap_err();
+    /// assert_eq!(error, OwnedFormParseError::Missing("page".into()));
+    /// ```
+    pub fn parse_pairs<'p, I, E>(pairs: I) -> Result<T, E>
+        where I: IntoIterator<Item = (&'p str, &'p str)>,
+              T: for<'f> FromForm<'f>,
+              for<'f> <T as FromForm<'f>>::Error: IntoOwned<Owned = E>
+    {
+        use crate::http::{RawStr, uri::Uri};
+
+        // Values are encoded so that `FromFormValue` decodes them back to the
+        // original value. Each `raw` is `key=value`; the key is `len` bytes.
+        let raws: Vec<(String, usize)> = pairs.into_iter()
+            .filter(|(key, value)| !key.is_empty() || !value.is_empty())
+            .map(|(key, value)| (format!("{}={}", key, Uri::percent_encode(value)), key.len()))
+            .collect();
+
+        let items: Vec<FormItem<'_>> = raws.iter()
+            .map(|(raw, len)| {
+                let raw = RawStr::from_str(raw);
+                FormItem { raw, key: (&raw[..*len]).into(), value: (&raw[(len + 1)..]).into() }
+            })
+            .collect();
+
+        T::from_form(&mut FormItems::from(&*items), true).map_err(|e| e.into_owned())
+    }
 }
 
 impl<T> Deref for Form<T> {
@@ -154,14 +214,26 @@ impl<T> DerefMut for Form<T> {
 
 impl<'f, T: FromForm<'f>> Form<T> {
     pub(crate) fn from_data(
+        request: &Request<'_>,
         form_str: &'f str,
         strict: bool
     ) -> Outcome<T, FormDataError<'f, T::Error>> {
         use self::FormDataError::*;
 
+        // Metrics are only measured when a sink is being managed.
+        let sink = request.managed_state::<FormMetricsSink>();
+        let start = sink.map(|_| Instant::now());
+
         let mut items = FormItems::from(form_str);
         let result = T::from_form(&mut items, strict);
-        if !items.exhaust() {
+        let complete = items.exhaust();
+        if let (Some(sink), Some(start)) = (sink, start) {
+            let elapsed = start.elapsed();
+            let (fields, bytes) = (items.yielded(), form_str.len());
+            FormMetrics::record(request, sink, FormMetrics { elapsed, fields, bytes });
+        }
+
+        if !complete {
             error_!("The request's form string was malformed.");
             return Failure((Status::BadRequest, Malformed(form_str)));
         }
@@ -182,13 +254,23 @@ impl<'f, T: FromForm<'f>> Form<T> {
 /// `application/x-www-form-urlencoded`, `Forward`s the request. If the form
 /// data cannot be parsed into a `T`, a `Failure` with status code
 /// `UnprocessableEntity` is returned. If the form string is malformed, a
-/// `Failure` with status code `BadRequest` is returned. Finally, if reading the
-/// incoming stream fails, returns a `Failure` with status code
-/// `InternalServerError`. In all failure cases, the raw form string is returned
+/// `Failure` with status code `BadRequest` is returned. If the request's
+/// `Content-Length` is malformed, a `Failure` with status code `BadRequest` and
+/// error [`FormDataError::Length`] is returned without reading any data, as the
+/// [`ContentLength`] request guard does. If the request's `Content-Length`
+/// exceeds the `forms` limit, a `Failure` with status code `PayloadTooLarge` is
+/// returned without reading any data; if the request has
+/// no `Content-Length`, the same failure is returned once the body is read past
+/// the limit. A body over the limit is never truncated. If the incoming stream
+/// ends before `Content-Length` bytes are read, a `Failure` with status
+/// code `BadRequest` and error [`FormDataError::Incomplete`] is returned.
+/// Finally, if reading the incoming stream fails, returns a `Failure` with
+/// status code `InternalServerError`. In all failure cases, the raw form string is returned
 /// if it was able to be retrieved from the incoming stream.
 ///
 /// All relevant warnings and errors are written to the console in Rocket
-/// logging format.
+/// logging format. If a [`FormMetricsSink`] is being managed, the form's
+/// [`FormMetrics`] are recorded once it has been parsed.
 impl<'r, T: FromForm<'r> + Send + 'r> FromTransformedData<'r> for Form<T> {
     type Error = FormDataError<'r, T::Error>;
     type Owned = String;
@@ -205,8 +287,35 @@ impl<'r, T: FromForm<'r> + Send + 'r> FromTransformedData<'r> for Form<T> {
             }
 
             let limit = request.limits().get("forms").unwrap_or(32.kibibytes());
-            match data.open(limit).stream_to_string().await {
-                Ok(form_string) => Transform::Borrowed(Success(form_string)),
+            let length = match ContentLength::from_request(request).await {
+                Success(ContentLength(length)) => length,
+                Failure((status, e)) => {
+                    return Transform::Borrowed(Failure((status, FormDataError::Length(e))));
+                }
+                Forward(()) => None,
+            };
+
+            if let Some(length) = length.filter(|&len| len > limit.as_u64()) {
+                error_!("Form data Content-Length ({}) exceeds limit ({}).", length, limit);
+                let err = (Status::PayloadTooLarge, FormDataError::TooLarge(length));
+                return Transform::Borrowed(Failure(err));
+            }
+
+            match data.open_within(limit).stream_to_string().await {
+                Ok(form_string) => match length {
+                    Some(length) if (form_string.len() as u64) < length => {
+                        let read = form_string.len() as u64;
+                        error_!("Form data ended after {} of {} bytes.", read, length);
+                        let err = (Status::BadRequest, FormDataError::Incomplete(read, length));
+                        Transform::Borrowed(Failure(err))
+                    }
+                    _ => Transform::Borrowed(Success(form_string)),
+                },
+                Err(e) if LimitExceeded::of(&e).is_some() => {
+                    error_!("Form data exceeds limit ({}).", limit);
+                    let err = FormDataError::TooLarge(limit.as_u64().saturating_add(1));
+                    Transform::Borrowed(Failure((Status::PayloadTooLarge, err)))
+                }
                 Err(e) => {
                     let err = (Status::InternalServerError, FormDataError::Io(e));
                     Transform::Borrowed(Failure(err))
@@ -216,11 +325,11 @@ impl<'r, T: FromForm<'r> + Send + 'r> FromTransformedData<'r> for Form<T> {
     }
 
     fn from_data(
-        _: &'r Request<'_>,
+        request: &'r Request<'_>,
         o: Transformed<'r, Self>
     ) -> FromDataFuture<'r, Self, Self::Error> {
         Box::pin(async move {
-            o.borrowed().and_then(|data| <Form<T>>::from_data(data, true).map(Form))
+            o.borrowed().and_then(|data| <Form<T>>::from_data(request, data, true).map(Form))
         })
     }
 }
@@ -102,12 +102,13 @@ use crate::http::RawStr;
 /// assert_eq!(items.next(), None);
 /// assert!(items.completed());
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum FormItems<'f> {
     #[doc(hidden)]
     Raw {
         string: &'f RawStr,
-        next_index: usize
+        next_index: usize,
+        yielded: usize
     },
     #[doc(hidden)]
     Cooked {
@@ -244,7 +245,7 @@ impl FormItems<'_> {
     #[inline]
     pub fn completed(&self) -> bool {
         match self {
-            FormItems::Raw { string, next_index } => *next_index >= string.len(),
+            FormItems::Raw { string, next_index, .. } => *next_index >= string.len(),
             FormItems::Cooked { items, next_index } => *next_index >= items.len(),
         }
     }
@@ -291,16 +292,24 @@ impl FormItems<'_> {
     #[doc(hidden)]
     pub fn mark_complete(&mut self) {
         match self {
-            FormItems::Raw { string, ref mut next_index } => *next_index = string.len(),
+            FormItems::Raw { string, ref mut next_index, .. } => *next_index = string.len(),
             FormItems::Cooked { items, ref mut next_index } => *next_index = items.len(),
         }
     }
+
+    /// Returns the number of key/value pairs this iterator has yielded.
+    pub(crate) fn yielded(&self) -> usize {
+        match self {
+            FormItems::Raw { yielded, .. } => *yielded,
+            FormItems::Cooked { next_index, .. } => *next_index,
+        }
+    }
 }
 
 impl<'f> From<&'f RawStr> for FormItems<'f> {
     #[inline(always)]
     fn from(string: &'f RawStr) -> FormItems<'f> {
-        FormItems::Raw { string, next_index: 0 }
+        FormItems::Raw { string, next_index: 0, yielded: 0 }
     }
 }
 
@@ -356,8 +365,10 @@ impl<'f> Iterator for FormItems<'f> {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
-            FormItems::Raw { ref mut string, ref mut next_index } => {
-                raw(string, next_index)
+            FormItems::Raw { ref mut string, ref mut next_index, ref mut yielded } => {
+                let item = raw(string, next_index);
+                *yielded += item.is_some() as usize;
+                item
             }
             FormItems::Cooked { items, ref mut next_index } => {
                 if *next_index < items.len() {
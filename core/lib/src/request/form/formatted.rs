@@ -0,0 +1,91 @@
+use std::convert::Infallible;
+
+/// Trait to parse a form value according to a format string.
+///
+/// This trait backs the `format` parameter of the [`FromForm`] derive's field
+/// attribute. A field annotated with `#[form(format = "...")]` is parsed by
+/// calling [`FromFormattedValue::parse_with_format()`] with the field's
+/// URL-decoded value and the format string in place of the field type's
+/// [`FromFormValue`] implementation. Annotating a field whose type doesn't
+/// implement this trait is a compile-time error.
+///
+/// Rocket implements this trait for the date and time types of the `time`
+/// crate, whose format strings are described in `time`'s documentation, and
+/// for `Option<T>` where `T: FromFormattedValue`:
+///
+///   * **Date**
+///   * **Time**
+///   * **PrimitiveDateTime**
+///   * **OffsetDateTime**
+///   * **Option&lt;T>** _where_ **T: FromFormattedValue**
+///
+///     The type of `T` is parsed with the format; if parsing fails, `None` is
+///     returned. A missing field is also `None`.
+///
+/// If parsing fails, the derived `FromForm` implementation fails with a
+/// [`FormParseError::BadValue`](crate::request::FormParseError::BadValue)
+/// naming the field.
+///
+/// [`FromForm`]: crate::request::FromForm
+/// [`FromFormValue`]: crate::request::FromFormValue
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use time::Date;
+///
+/// #[derive(FromForm)]
+/// struct Booking {
+///     #[form(format = "%m/%d/%Y")]
+///     arrival: Date,
+///     #[form(format = "%m/%d/%Y")]
+///     departure: Option<Date>,
+/// }
+/// ```
+pub trait FromFormattedValue: Sized {
+    /// The associated error which can be returned from parsing.
+    type Error;
+
+    /// Parses an instance of `Self` from the URL-decoded form value `value`
+    /// according to the format string `format`.
+    fn parse_with_format(value: &str, format: &str) -> Result<Self, Self::Error>;
+
+    /// Returns a default value to be used when the form field does not exist.
+    /// If this returns `None`, then the field is required. Otherwise, this
+    /// should return `Some(default_value)`. The default implementation simply
+    /// returns `None`.
+    #[inline(always)]
+    fn default() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_with_time_parse {
+    ($($T:ty),*) => ($(
+        impl FromFormattedValue for $T {
+            type Error = time::ParseError;
+
+            #[inline(always)]
+            fn parse_with_format(value: &str, format: &str) -> Result<Self, Self::Error> {
+                <$T>::parse(value, format)
+            }
+        }
+    )*)
+}
+
+impl_with_time_parse!(time::Date, time::Time, time::PrimitiveDateTime, time::OffsetDateTime);
+
+impl<T: FromFormattedValue> FromFormattedValue for Option<T> {
+    type Error = Infallible;
+
+    #[inline(always)]
+    fn parse_with_format(value: &str, format: &str) -> Result<Self, Self::Error> {
+        Ok(T::parse_with_format(value, format).ok())
+    }
+
+    #[inline(always)]
+    fn default() -> Option<Option<T>> {
+        Some(None)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::request::FormItems;
+use crate::request::{FormItems, FormParseError};
 
 /// Trait to create an instance of some type from an HTTP form.
 /// [`Form`](crate::request::Form) requires its generic type to implement this trait.
@@ -108,6 +108,27 @@ pub trait FromForm<'f>: Sized {
     fn from_form(it: &mut FormItems<'f>, strict: bool) -> Result<Self, Self::Error>;
 }
 
+/// Parses a form without fields. Used as `Form<()>`, this asserts that a
+/// submitted form is empty.
+///
+/// When `strict` is `true`, the first field present, other than `_method`,
+/// results in a [`FormParseError::Unknown`] error. Otherwise, all fields are
+/// ignored and parsing always succeeds.
+impl<'f> FromForm<'f> for () {
+    type Error = FormParseError<'f>;
+
+    #[inline]
+    fn from_form(items: &mut FormItems<'f>, strict: bool) -> Result<(), Self::Error> {
+        for (key, value) in items.map(|item| item.key_value()) {
+            if strict && key != "_method" {
+                return Err(FormParseError::Unknown(key, value));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<'f, T: FromForm<'f>> FromForm<'f> for Option<T> {
     type Error = std::convert::Infallible;
 
@@ -1,4 +1,6 @@
 use std::str::FromStr;
+use std::borrow::Cow;
+use std::time::Duration;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr};
 use std::num::{
     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
@@ -93,6 +95,78 @@ use crate::http::RawStr;
 ///     type returns successfully. Otherwise, the raw form value is returned as
 ///     the `Err` value.
 ///
+///   * **Saturating&lt;T&gt;** _for the primitive integer types `T`, with
+///     Rust 1.74 or later_
+///
+///     The form value is parsed as a `T`, and the result is wrapped. Parsing
+///     does not saturate: a value that is out of range for `T` is invalid, and
+///     the raw form value is returned as the `Err` value.
+///
+///   * **Duration**
+///
+///     URL decodes the form value and parses it as a number of seconds, as in
+///     `90`, or as a sequence of amounts with units, as in `30s` or `2h30m`.
+///     The units are `h`, `m`, `s`, and `ms`; each may appear at most once, in
+///     that order, and amounts are unsigned integers. If decoding or parsing
+///     fails, including when the duration overflows, the raw form value is
+///     returned as the `Err` value.
+///
+///   * **BigInt, BigUint** _from `num-bigint`, with the `bigint` feature_
+///
+///     URL decodes the form value and parses it as a decimal integer or, if it
+///     is prefixed with `0x` or `0X`, as a hexadecimal integer. A `BigInt` may
+///     be preceded by a sign, as in `-0x1f`. If decoding or parsing fails, the
+///     raw form value is returned as the `Err` value.
+///
+///   * **NaiveDate, NaiveDateTime, DateTime&lt;Utc&gt;** _from `chrono`, with
+///     the `chrono` feature_
+///
+///     URL decodes the form value and parses it as an ISO 8601 date
+///     (`2021-03-14`), an ISO 8601 local date and time with or without seconds
+///     (`2021-03-14T15:09` or `2021-03-14T15:09:26.5`), or an RFC 3339 date and
+///     time with an offset (`2021-03-14T15:09:26+01:00`), respectively. The
+///     latter is converted to UTC. The formats for dates and local dates and
+///     times match those submitted by HTML `date` and `datetime-local` inputs.
+///     If decoding or parsing fails, the raw form value is returned as the
+///     `Err` value.
+///
+///   * **Uuid** _from `uuid`, with the `uuid` feature_
+///
+///     Parses the form value as a UUID in its canonical hyphenated form, as in
+///     `67e55044-10b1-426f-9247-bb680e5fe0c8`, in either case. Other forms,
+///     such as the simple form without hyphens and forms with braces or a
+///     `urn:uuid:` prefix, are rejected; use `parse_with` to accept them. The
+///     form value is not URL decoded, as a canonical UUID never needs to be
+///     encoded. If parsing fails, the raw form value is returned as the `Err`
+///     value.
+///
+///   * **Date, PrimitiveDateTime** _from `time`_
+///
+///     URL decodes the form value and parses it as an ISO 8601 date, as in
+///     `2021-03-14`, or an ISO 8601 local date and time with or without
+///     seconds, as in `2021-03-14T15:09` or `2021-03-14T15:09:26`,
+///     respectively: the formats submitted by HTML `date` and `datetime-local`
+///     inputs. Values with trailing characters are rejected. If decoding or
+///     parsing fails, the raw form value is returned as the `Err` value.
+///
+///   * **Time** _from `time`_
+///
+///     URL decodes the form value and parses it as a time of day with or
+///     without seconds, as in `14:30` or `14:30:05`, the formats submitted by
+///     HTML `time` inputs. Values with trailing characters are rejected. If
+///     decoding or parsing fails, the raw form value is returned as the `Err`
+///     value.
+///
+///   * **OffsetDateTime** _from `time`_
+///
+///     URL decodes the form value and parses it as an RFC 3339 date and time
+///     with seconds and an explicit offset, as in `2021-03-01T14:30:00+02:00`
+///     or `2021-03-01T12:30:00Z`. A value without an offset is rejected rather
+///     than assumed to be in UTC; use a `PrimitiveDateTime` for offset-naive
+///     values. Note that a `+` must be percent-encoded as `%2B` to be
+///     distinguished from a space. If decoding or parsing fails, the raw form
+///     value is returned as the `Err` value.
+///
 ///   * **bool**
 ///
 ///     A value is validated successfully as `true` if the the form value is
@@ -106,6 +180,15 @@ use crate::http::RawStr;
 ///
 ///     The raw, undecoded string is returned directly without modification.
 ///
+///   * **Cow&lt;[u8]>**
+///
+///     _This implementation always returns successfully._
+///
+///     The bytes of the raw, undecoded form value are returned, borrowed,
+///     without modification. This is useful when the exact bytes that were
+///     submitted are required, such as when verifying a signature over a field.
+///     Note that percent-encoded sequences and `+` are _not_ decoded.
+///
 ///   * **String**
 ///
 ///     URL decodes the form value. If the decode is successful, the decoded
@@ -199,6 +282,16 @@ impl<'v> FromFormValue<'v> for &'v RawStr {
     }
 }
 
+impl<'v> FromFormValue<'v> for Cow<'v, [u8]> {
+    type Error = std::convert::Infallible;
+
+    // This just gives the raw bytes.
+    #[inline(always)]
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        Ok(Cow::Borrowed(v.as_bytes()))
+    }
+}
+
 impl<'v> FromFormValue<'v> for String {
     type Error = &'v RawStr;
 
@@ -263,6 +356,243 @@ impl_with_fromstr_encoded!(
     IpAddr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr
 );
 
+#[cfg(saturating)]
+macro_rules! impl_saturating {
+    ($($T:ident),+) => ($(
+        impl<'v> FromFormValue<'v> for std::num::Saturating<$T> {
+            type Error = &'v RawStr;
+
+            #[inline(always)]
+            fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+                $T::from_form_value(v).map(std::num::Saturating)
+            }
+        }
+    )+)
+}
+
+#[cfg(saturating)]
+impl_saturating!(isize, i8, i16, i32, i64, i128, usize, u8, u16, u32, u64, u128);
+
+/// Parses a number of seconds or a duration like `2h30m`, with units in
+/// decreasing order. Returns `None` on overflow.
+fn parse_duration(string: &str) -> Option<Duration> {
+    // `u64::from_str` accepts a leading `+`, which we don't.
+    if !string.is_empty() && string.bytes().all(|b| b.is_ascii_digit()) {
+        return string.parse().ok().map(Duration::from_secs);
+    }
+
+    let (mut rest, mut total, mut last_unit) = (string, Duration::from_secs(0), None);
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let units = rest[digits..].find(|c: char| !c.is_ascii_alphabetic())
+            .map_or(rest.len(), |i| digits + i);
+
+        let amount = rest[..digits].parse::<u64>().ok()?;
+        let (unit, duration) = match &rest[digits..units] {
+            "h" => (0, Duration::from_secs(amount.checked_mul(60 * 60)?)),
+            "m" => (1, Duration::from_secs(amount.checked_mul(60)?)),
+            "s" => (2, Duration::from_secs(amount)),
+            "ms" => (3, Duration::from_millis(amount)),
+            _ => return None,
+        };
+
+        if last_unit.map_or(false, |last| last >= unit) {
+            return None;
+        }
+
+        total = total.checked_add(duration)?;
+        last_unit = Some(unit);
+        rest = &rest[units..];
+    }
+
+    last_unit.map(|_| total)
+}
+
+impl<'v> FromFormValue<'v> for Duration {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        let decoded = v.url_decode().map_err(|_| v)?;
+        parse_duration(&decoded).ok_or(v)
+    }
+}
+
+/// Parses a decimal or `0x`-prefixed hexadecimal unsigned integer.
+#[cfg(feature = "bigint")]
+fn parse_biguint(string: &str) -> Option<num_bigint::BigUint> {
+    let (digits, radix) = match string.strip_prefix("0x").or_else(|| string.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (string, 10),
+    };
+
+    // `parse_bytes` accepts a leading `+`; signs are handled by the caller.
+    if !digits.starts_with(|c: char| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    num_bigint::BigUint::parse_bytes(digits.as_bytes(), radix)
+}
+
+#[cfg(feature = "bigint")]
+#[cfg_attr(nightly, doc(cfg(feature = "bigint")))]
+impl<'v> FromFormValue<'v> for num_bigint::BigUint {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        let decoded = v.url_decode().map_err(|_| v)?;
+        parse_biguint(decoded.strip_prefix('+').unwrap_or(decoded.as_str())).ok_or(v)
+    }
+}
+
+#[cfg(feature = "bigint")]
+#[cfg_attr(nightly, doc(cfg(feature = "bigint")))]
+impl<'v> FromFormValue<'v> for num_bigint::BigInt {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        use num_bigint::{BigInt, Sign};
+
+        let decoded = v.url_decode().map_err(|_| v)?;
+        let (sign, magnitude) = match decoded.strip_prefix('-') {
+            Some(magnitude) => (Sign::Minus, magnitude),
+            None => (Sign::Plus, decoded.strip_prefix('+').unwrap_or(decoded.as_str())),
+        };
+
+        parse_biguint(magnitude).map(|n| BigInt::from_biguint(sign, n)).ok_or(v)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+impl<'v> FromFormValue<'v> for chrono::NaiveDate {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        let decoded = v.url_decode().map_err(|_| v)?;
+        chrono::NaiveDate::parse_from_str(&decoded, "%Y-%m-%d").map_err(|_| v)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+impl<'v> FromFormValue<'v> for chrono::NaiveDateTime {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        use chrono::NaiveDateTime;
+
+        // HTML `datetime-local` inputs omit the seconds when they're zero.
+        let decoded = v.url_decode().map_err(|_| v)?;
+        NaiveDateTime::parse_from_str(&decoded, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(&decoded, "%Y-%m-%dT%H:%M"))
+            .map_err(|_| v)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(nightly, doc(cfg(feature = "chrono")))]
+impl<'v> FromFormValue<'v> for chrono::DateTime<chrono::Utc> {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        let decoded = v.url_decode().map_err(|_| v)?;
+        chrono::DateTime::parse_from_rfc3339(&decoded)
+            .map(|datetime| datetime.with_timezone(&chrono::Utc))
+            .map_err(|_| v)
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(nightly, doc(cfg(feature = "uuid")))]
+impl<'v> FromFormValue<'v> for uuid::Uuid {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        // `parse_str` also accepts the simple form, without hyphens.
+        let hyphens = [8, 13, 18, 23];
+        let canonical = v.len() == 36 && hyphens.iter().all(|&i| v.as_bytes()[i] == b'-');
+        match canonical {
+            true => uuid::Uuid::parse_str(v.as_str()).map_err(|_| v),
+            false => Err(v),
+        }
+    }
+}
+
+impl<'v> FromFormValue<'v> for time::Date {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        // `YYYY-MM-DD`; the length rules out trailing characters.
+        let decoded = v.url_decode().map_err(|_| v)?;
+        if decoded.len() != 10 {
+            return Err(v);
+        }
+
+        time::Date::parse(&decoded, "%F").map_err(|_| v)
+    }
+}
+
+impl<'v> FromFormValue<'v> for time::PrimitiveDateTime {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        // `YYYY-MM-DDTHH:MM` or `YYYY-MM-DDTHH:MM:SS`.
+        let decoded = v.url_decode().map_err(|_| v)?;
+        let format = match decoded.len() {
+            16 => "%FT%R",
+            19 => "%FT%T",
+            _ => return Err(v),
+        };
+
+        time::PrimitiveDateTime::parse(&decoded, format).map_err(|_| v)
+    }
+}
+
+impl<'v> FromFormValue<'v> for time::Time {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        // `HH:MM` or `HH:MM:SS`; the length rules out trailing characters.
+        let decoded = v.url_decode().map_err(|_| v)?;
+        let format = match decoded.len() {
+            5 => "%R",
+            8 => "%T",
+            _ => return Err(v),
+        };
+
+        time::Time::parse(&decoded, format).map_err(|_| v)
+    }
+}
+
+impl<'v> FromFormValue<'v> for time::OffsetDateTime {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        // `YYYY-MM-DDTHH:MM:SS` followed by `Z` or `+HH:MM`, which `%z` expects
+        // to be written as `+0000` or `+HHMM`, respectively.
+        let decoded = v.url_decode().map_err(|_| v)?;
+        let (datetime, offset) = match decoded.strip_suffix(|c| c == 'Z' || c == 'z') {
+            Some(datetime) => (datetime, "+0000".to_string()),
+            None => match decoded.char_indices().rev().nth(5) {
+                Some((i, c)) if c == '+' || c == '-' => {
+                    let (datetime, offset) = decoded.split_at(i);
+                    match offset.as_bytes()[3] {
+                        b':' => (datetime, offset.replacen(':', "", 1)),
+                        _ => return Err(v),
+                    }
+                }
+                _ => return Err(v),
+            },
+        };
+
+        if datetime.len() != 19 {
+            return Err(v);
+        }
+
+        time::OffsetDateTime::parse(format!("{}{}", datetime, offset), "%FT%T%z").map_err(|_| v)
+    }
+}
+
 impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Option<T> {
     type Error = std::convert::Infallible;
 
@@ -292,3 +622,33 @@ impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Result<T, T::Error> {
         }
     }
 }
+
+// The `saturating` cfg is only set for this library, not its integration
+// tests, so these tests live here.
+#[cfg(saturating)]
+#[cfg(test)]
+mod saturating_tests {
+    use std::num::Saturating;
+    use super::FromFormValue;
+
+    #[test]
+    fn saturating_integers_parse() {
+        assert_eq!(Saturating::<u16>::from_form_value("65535".into()), Ok(Saturating(65535)));
+        assert_eq!(Saturating::<u16>::from_form_value("0".into()), Ok(Saturating(0)));
+
+        let hits = Saturating::<u16>::from_form_value("65535".into()).unwrap();
+        let delta = Saturating::<i8>::from_form_value("-5".into()).unwrap();
+        assert_eq!(delta, Saturating(-5));
+
+        // The parsed values saturate in arithmetic.
+        assert_eq!(hits + Saturating(1), Saturating(u16::MAX));
+        assert_eq!(delta * Saturating(100), Saturating(i8::MIN));
+    }
+
+    #[test]
+    fn out_of_range_integers_fail() {
+        for value in &["65536", "-1", "", "1.5", "abc"] {
+            assert_eq!(Saturating::<u16>::from_form_value((*value).into()), Err((*value).into()));
+        }
+    }
+}
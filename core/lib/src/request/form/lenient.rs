@@ -108,9 +108,9 @@ impl<'r, T: FromForm<'r> + Send + 'r> FromTransformedData<'r> for LenientForm<T>
         <Form<T>>::transform(r, d)
     }
 
-    fn from_data(_: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
         Box::pin(futures::future::ready(o.borrowed().and_then(|form| {
-            <Form<T>>::from_data(form, false).map(LenientForm)
+            <Form<T>>::from_data(r, form, false).map(LenientForm)
         })))
     }
 }
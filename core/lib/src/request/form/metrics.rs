@@ -0,0 +1,108 @@
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::request::Request;
+
+/// Measurements of a form parse by [`Form`](crate::request::Form) or
+/// [`LenientForm`](crate::request::LenientForm).
+///
+/// Metrics are only recorded when form metrics are enabled by managing a
+/// [`FormMetricsSink`]. Once the form has been parsed, the metrics are passed
+/// to the sink and cached in the request, where they can be retrieved with
+/// [`FormMetrics::of()`]. Metrics are recorded whether or not the form parses
+/// successfully.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormMetrics {
+    /// The time spent parsing the form string into the target type.
+    pub elapsed: Duration,
+    /// The number of key/value pairs read from the form string.
+    pub fields: usize,
+    /// The length, in bytes, of the form string.
+    pub bytes: usize,
+}
+
+/// The request-local slot for the metrics of the request's form.
+struct Recorded(Mutex<Option<FormMetrics>>);
+
+impl Recorded {
+    fn of<'r>(request: &'r Request<'_>) -> &'r Recorded {
+        request.local_cache(|| Recorded(Mutex::new(None)))
+    }
+}
+
+impl FormMetrics {
+    /// Returns the metrics recorded for the form parsed in `request`, if any.
+    ///
+    /// Returns `None` if form metrics are not enabled or if no form has been
+    /// parsed yet. A response fairing is a good place to call this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::AdHoc;
+    /// use rocket::request::{FormMetrics, FormMetricsSink};
+    ///
+    /// let rocket = rocket::ignite()
+    ///     .manage(FormMetricsSink::default())
+    ///     .attach(AdHoc::on_response("Form Timing", |req, _| Box::pin(async move {
+    ///         if let Some(metrics) = FormMetrics::of(req) {
+    ///             println!("form parsed in {:?}", metrics.elapsed);
+    ///         }
+    ///     })));
+    /// ```
+    pub fn of(request: &Request<'_>) -> Option<FormMetrics> {
+        *Recorded::of(request).0.lock().expect("form metrics lock")
+    }
+
+    pub(crate) fn record(request: &Request<'_>, sink: &FormMetricsSink, metrics: FormMetrics) {
+        *Recorded::of(request).0.lock().expect("form metrics lock") = Some(metrics);
+        if let Some(callback) = &sink.callback {
+            callback(request, &metrics);
+        }
+    }
+}
+
+/// Enables [`FormMetrics`] when managed by a Rocket instance.
+///
+/// When no `FormMetricsSink` is managed, form guards skip all measurements. A
+/// sink created with [`FormMetricsSink::default()`] only caches metrics in the
+/// request; one created with [`FormMetricsSink::new()`] additionally calls a
+/// function with the metrics of every parsed form.
+///
+/// # Example
+///
+/// Logging forms that take longer than 10ms to parse:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use rocket::request::FormMetricsSink;
+///
+/// let rocket = rocket::ignite()
+///     .manage(FormMetricsSink::new(|req, metrics| {
+///         if metrics.elapsed > Duration::from_millis(10) {
+///             println!("slow form at {}: {:?}", req.uri(), metrics);
+///         }
+///     }));
+/// ```
+#[derive(Default)]
+pub struct FormMetricsSink {
+    callback: Option<Box<dyn Fn(&Request<'_>, &FormMetrics) + Send + Sync>>,
+}
+
+impl FormMetricsSink {
+    /// Creates a sink that calls `f` with the metrics of every parsed form.
+    pub fn new<F>(f: F) -> FormMetricsSink
+        where F: Fn(&Request<'_>, &FormMetrics) + Send + Sync + 'static
+    {
+        FormMetricsSink { callback: Some(Box::new(f)) }
+    }
+}
+
+impl fmt::Debug for FormMetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormMetricsSink")
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
+}
@@ -6,10 +6,31 @@ mod from_form_value;
 mod lenient;
 mod error;
 mod form;
+mod relative_path;
+mod metrics;
+mod subnet;
+mod range;
+mod one_of;
+#[cfg(feature = "regex")] mod pattern;
+mod present;
+mod raw;
+mod empty_as_none;
+mod formatted;
 
 pub use self::form_items::{FormItems, FormItem};
 pub use self::from_form::FromForm;
 pub use self::from_form_value::FromFormValue;
 pub use self::form::Form;
 pub use self::lenient::LenientForm;
-pub use self::error::{FormError, FormParseError, FormDataError};
+pub use self::relative_path::RelativePath;
+pub use self::error::{FormError, FormParseError, FormDataError, UnknownValue};
+pub use self::error::{RequiredFields, OwnedFormParseError};
+pub use self::metrics::{FormMetrics, FormMetricsSink};
+pub use self::subnet::{Subnet, SubnetError, in_subnet};
+pub use self::range::{RangeError, in_range};
+pub use self::one_of::one_of;
+#[cfg(feature = "regex")] pub use self::pattern::{PatternMismatch, matching};
+pub use self::present::Present;
+pub use self::raw::RawForm;
+pub use self::empty_as_none::EmptyAsNone;
+pub use self::formatted::FromFormattedValue;
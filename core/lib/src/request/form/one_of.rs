@@ -0,0 +1,46 @@
+use crate::request::UnknownValue;
+
+/// Returns a parser for strings in the fixed set `values`, for use with the
+/// `parse_with` field option of the [`FromForm`](crate::request::FromForm)
+/// derive.
+///
+/// The parser accepts the URL-decoded value of the field if it is exactly
+/// equal to one of `values`. Otherwise, it returns an [`UnknownValue`] listing
+/// the accepted values, and the form fails to parse with a
+/// [`FormParseError::BadValue`](crate::request::FormParseError::BadValue).
+/// Unlike the values of an enum deriving `FromFormValue`, the values are
+/// matched case-sensitively.
+///
+/// This is an alternative to deriving `FromFormValue` for an enum when the
+/// field is otherwise used as a string or when a set of values is shared by
+/// several fields.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::one_of;
+///
+/// const COLORS: &[&str] = &["red", "green", "blue"];
+///
+/// #[derive(FromForm)]
+/// struct Paint {
+///     #[form(parse_with = "one_of(COLORS)")]
+///     color: String,
+///     #[form(parse_with = r#"one_of(&["matte", "gloss"])"#)]
+///     finish: String,
+/// }
+///
+/// let parse = one_of(COLORS);
+/// assert_eq!(parse("red"), Ok("red".to_string()));
+/// assert_eq!(parse("Red").unwrap_err().to_string(),
+///     "unknown value \"Red\"; expected one of: red, green, blue");
+/// ```
+pub fn one_of(values: &'static [&'static str]) -> impl Fn(&str) -> Result<String, UnknownValue<'_>> {
+    move |value| {
+        match values.contains(&value) {
+            true => Ok(value.to_string()),
+            false => Err(UnknownValue { value: value.into(), expected: values }),
+        }
+    }
+}
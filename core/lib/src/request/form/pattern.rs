@@ -0,0 +1,76 @@
+use std::fmt;
+
+use regex_crate::Regex;
+
+/// Error returned when a value fails to validate with [`matching()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMismatch {
+    /// The value that was rejected.
+    pub value: String,
+    /// The pattern the value failed to match.
+    pub pattern: String,
+}
+
+impl fmt::Display for PatternMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} does not match the pattern `{}`", self.value, self.pattern)
+    }
+}
+
+impl std::error::Error for PatternMismatch { }
+
+/// Returns a parser for strings matching the regular expression `pattern`, for
+/// use with the `parse_with` field option of the
+/// [`FromForm`](crate::request::FromForm) derive.
+///
+/// The parser accepts the URL-decoded value of the field if `pattern` matches
+/// it. Otherwise, it returns a [`PatternMismatch`], and the form fails to parse
+/// with a [`FormParseError::BadValue`](crate::request::FormParseError::BadValue).
+/// The pattern is compiled once, by this function, and the returned parser
+/// reuses it for every value. The derive calls this function once per field,
+/// the first time the form is parsed, so each pattern is compiled only once.
+///
+/// **Note:** Like any regular expression, the pattern matches a value if it
+/// matches _any part_ of the value. To require that the entire value match,
+/// anchor the pattern with `^` and `$`, as in the example below.
+///
+/// This function is only available when the `regex` feature is enabled.
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression. When `pattern` is a
+/// string literal in a `parse_with` option that calls this function by its
+/// full path, `rocket::request::matching`, the derive instead checks it when
+/// the form is compiled and reports an invalid pattern as a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::matching;
+///
+/// #[derive(FromForm)]
+/// struct Signup {
+///     #[form(parse_with = r#"matching(r"^[a-z0-9_]+$")"#)]
+///     username: String,
+/// }
+///
+/// let parse = matching(r"^[a-z0-9_]+$");
+/// assert_eq!(parse("bob_99"), Ok("bob_99".to_string()));
+/// assert!(parse("Bob!").is_err());
+/// ```
+#[cfg_attr(nightly, doc(cfg(feature = "regex")))]
+pub fn matching(pattern: &str) -> impl Fn(&str) -> Result<String, PatternMismatch> {
+    let regex = Regex::new(pattern)
+        .unwrap_or_else(|e| panic!("invalid pattern {:?}: {}", pattern, e));
+
+    move |value| {
+        match regex.is_match(value) {
+            true => Ok(value.to_string()),
+            false => {
+                let pattern = regex.as_str().to_string();
+                Err(PatternMismatch { value: value.into(), pattern })
+            }
+        }
+    }
+}
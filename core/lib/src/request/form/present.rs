@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::ops::{Deref, DerefMut};
+
+use crate::request::{FromForm, FormItems};
+
+/// A [`FromForm`] wrapper that records which fields were actually submitted.
+///
+/// `Present<T>` parses a `T` exactly as `T` would while also recording the
+/// URL-decoded names of the fields present in the form, other than `_method`.
+/// The set is independent of the fields `T` defines: it contains only what the
+/// client sent. This is useful for `PATCH`-style handlers that apply only the
+/// fields a client provided, particularly when a field's absence and its
+/// default value must be told apart.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Form, Present};
+///
+/// #[derive(FromForm)]
+/// struct ProfilePatch {
+///     name: Option<String>,
+///     bio: Option<String>,
+///     public: bool,
+/// }
+///
+/// #[patch("/profile", data = "<patch>")]
+/// fn patch(patch: Form<Present<ProfilePatch>>) -> String {
+///     // `public` defaults to `false`, so check whether it was submitted.
+///     if patch.contains("public") {
+///         return format!("visibility: {}", patch.public);
+///     }
+///
+///     format!("updated {} field(s)", patch.fields().len())
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Present<T> {
+    value: T,
+    fields: HashSet<String>,
+}
+
+impl<T> Present<T> {
+    /// Returns the URL-decoded names of the submitted fields.
+    #[inline(always)]
+    pub fn fields(&self) -> &HashSet<String> {
+        &self.fields
+    }
+
+    /// Returns `true` if a field named `name` was submitted.
+    #[inline(always)]
+    pub fn contains(&self, name: &str) -> bool {
+        self.fields.contains(name)
+    }
+
+    /// Consumes `self` and returns the parsed value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Consumes `self` and returns the parsed value and the set of submitted
+    /// field names.
+    #[inline(always)]
+    pub fn into_parts(self) -> (T, HashSet<String>) {
+        (self.value, self.fields)
+    }
+}
+
+impl<T> Deref for Present<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Present<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+/// Records the names of the fields in the form, then parses a `T`. Fails
+/// exactly when `T` fails.
+impl<'f, T: FromForm<'f>> FromForm<'f> for Present<T> {
+    type Error = T::Error;
+
+    fn from_form(items: &mut FormItems<'f>, strict: bool) -> Result<Self, Self::Error> {
+        let fields = items.clone()
+            .filter(|item| item.key != "_method")
+            .map(|item| item.key.url_decode_lossy())
+            .collect();
+
+        let value = T::from_form(items, strict)?;
+        Ok(Present { value, fields })
+    }
+}
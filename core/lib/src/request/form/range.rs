@@ -0,0 +1,94 @@
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Bound, RangeBounds};
+
+/// Error returned when validating a value with [`in_range()`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeError<T, E> {
+    /// The value could not be parsed; `.0` is the parse error.
+    Invalid(E),
+    /// The value `.0` is outside of the permitted bounds `.1`.
+    Outside(T, (Bound<T>, Bound<T>)),
+}
+
+impl<T: fmt::Display, E: fmt::Display> fmt::Display for RangeError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, (start, end)) = match self {
+            RangeError::Invalid(e) => return write!(f, "invalid value: {}", e),
+            RangeError::Outside(value, bounds) => (value, bounds),
+        };
+
+        write!(f, "{} is out of range: must be", value)?;
+        match start {
+            Bound::Included(start) => write!(f, " at least {}", start)?,
+            Bound::Excluded(start) => write!(f, " greater than {}", start)?,
+            Bound::Unbounded => {}
+        }
+
+        if !matches!(start, Bound::Unbounded) && !matches!(end, Bound::Unbounded) {
+            write!(f, " and")?;
+        }
+
+        match end {
+            Bound::Included(end) => write!(f, " at most {}", end),
+            Bound::Excluded(end) => write!(f, " less than {}", end),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+impl<T, E> std::error::Error for RangeError<T, E>
+    where T: fmt::Debug + fmt::Display, E: fmt::Debug + fmt::Display { }
+
+fn cloned<T: Clone>(bound: Bound<&T>) -> Bound<T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value.clone()),
+        Bound::Excluded(value) => Bound::Excluded(value.clone()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Returns a parser for numbers in `range`, for use with the `parse_with`
+/// field option of the [`FromForm`](crate::request::FromForm) derive.
+///
+/// The parser parses the URL-decoded value of the field as a `T` with `T`'s
+/// [`FromStr`] implementation and accepts it if it is contained in `range`,
+/// which may be any of Rust's range types, inclusive or exclusive, bounded or
+/// not. Otherwise, it returns a [`RangeError`] describing the value and the
+/// permitted bounds, and the form fails to parse with a
+/// [`FormParseError::BadValue`](crate::request::FormParseError::BadValue).
+/// Floating-point `NaN` is never in range.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::in_range;
+///
+/// #[derive(FromForm)]
+/// struct Survey {
+///     #[form(parse_with = "in_range(1..=10)")]
+///     rating: u8,
+///     #[form(parse_with = "in_range(0.0..1.0)")]
+///     ratio: f64,
+/// }
+///
+/// let parse = in_range(1..=10);
+/// assert_eq!(parse("7"), Ok(7));
+/// assert_eq!(parse("11").unwrap_err().to_string(),
+///     "11 is out of range: must be at least 1 and at most 10");
+/// ```
+pub fn in_range<T, R>(range: R) -> impl Fn(&str) -> Result<T, RangeError<T, T::Err>>
+    where T: FromStr + PartialOrd + Clone, R: RangeBounds<T>
+{
+    move |value| {
+        let value: T = value.parse().map_err(RangeError::Invalid)?;
+        match range.contains(&value) {
+            true => Ok(value),
+            false => {
+                let bounds = (cloned(range.start_bound()), cloned(range.end_bound()));
+                Err(RangeError::Outside(value, bounds))
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::request::{Request, form::{Form, FormDataError, FromForm}};
+use crate::data::{Data, Transformed, FromTransformedData, TransformFuture, FromDataFuture};
+
+/// A data guard that parses a form and retains its raw body.
+///
+/// `RawForm<T>` parses a `T` exactly as [`Form<T>`] does, strictly, while also
+/// retaining the request body exactly as it was received, before any
+/// URL-decoding. The raw body is needed to verify signatures, such as webhook
+/// HMACs, that are computed over the bytes sent by the client. Because a body
+/// can only be read once, such a signature can't be verified after `Form<T>`
+/// has consumed it.
+///
+/// The raw body borrows from the same buffer the form is parsed from, so
+/// retaining it requires no additional memory. Like `Form<T>`, the size of the
+/// body is limited by the `forms` limit, which defaults to 32KiB. A body over
+/// the limit is rejected, never truncated, so the raw body is always the entire
+/// body the client sent.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::RawForm;
+///
+/// #[derive(FromForm)]
+/// struct Event {
+///     kind: String,
+///     signature: String,
+/// }
+///
+/// # fn verify(_: &[u8], _: &str) -> bool { true }
+/// #[post("/webhook", data = "<event>")]
+/// fn webhook(event: RawForm<'_, Event>) -> Option<String> {
+///     if !verify(event.raw(), &event.signature) {
+///         return None;
+///     }
+///
+///     Some(format!("received {}", event.kind))
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug)]
+pub struct RawForm<'r, T> {
+    value: T,
+    raw: &'r str,
+}
+
+impl<'r, T> RawForm<'r, T> {
+    /// Returns the raw, undecoded request body.
+    #[inline(always)]
+    pub fn raw(&self) -> &'r [u8] {
+        self.raw.as_bytes()
+    }
+
+    /// Returns the raw, undecoded request body as a string.
+    #[inline(always)]
+    pub fn raw_str(&self) -> &'r str {
+        self.raw
+    }
+
+    /// Consumes `self` and returns the parsed value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Consumes `self` and returns the parsed value and the raw body.
+    #[inline(always)]
+    pub fn into_parts(self) -> (T, &'r [u8]) {
+        (self.value, self.raw.as_bytes())
+    }
+}
+
+impl<T> Deref for RawForm<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for RawForm<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<'r, T: FromForm<'r> + Send + 'r> FromTransformedData<'r> for RawForm<'r, T> {
+    type Error = FormDataError<'r, T::Error>;
+    type Owned = String;
+    type Borrowed = str;
+
+    fn transform(r: &'r Request<'_>, d: Data) -> TransformFuture<'r, Self::Owned, Self::Error> {
+        <Form<T>>::transform(r, d)
+    }
+
+    fn from_data(r: &'r Request<'_>, o: Transformed<'r, Self>) -> FromDataFuture<'r, Self, Self::Error> {
+        Box::pin(futures::future::ready(o.borrowed().and_then(|raw| {
+            <Form<T>>::from_data(r, raw, true).map(|value| RawForm { value, raw })
+        })))
+    }
+}
@@ -0,0 +1,95 @@
+use std::ops::Deref;
+use std::path::{Component, Path, PathBuf};
+
+use crate::http::RawStr;
+use crate::request::FromFormValue;
+
+/// A normalized, relative path parsed from a form value.
+///
+/// A `RelativePath` is suitable for form fields that name a location beneath
+/// some base directory, such as a storage subpath. A form value parses as a
+/// `RelativePath` only if, after percent-decoding, it is a non-empty path
+/// consisting solely of normal components. In particular, the following are
+/// rejected:
+///
+///   * parent (`..`) and current (`.`) directory components
+///   * absolute paths, such as `/etc/passwd`
+///   * drive or UNC prefixes, such as `C:` or `\\server`, on _any_ platform
+///   * components containing a `\` or `:`, or a NUL byte
+///
+/// On error, the raw form value is returned.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Form, RelativePath};
+///
+/// #[derive(FromForm)]
+/// struct Upload {
+///     dest: RelativePath,
+/// }
+///
+/// #[post("/upload", data = "<upload>")]
+/// fn upload(upload: Form<Upload>) -> String {
+///     let path = std::path::Path::new("/srv/uploads").join(&*upload.dest);
+///     format!("Storing at {}", path.display())
+/// }
+/// # fn main() { }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelativePath(PathBuf);
+
+impl RelativePath {
+    /// Consumes `self` and returns the inner `PathBuf`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{FromFormValue, RelativePath};
+    /// use rocket::http::RawStr;
+    ///
+    /// let path = RelativePath::from_form_value(RawStr::from_str("a%2Fb.txt")).unwrap();
+    /// assert_eq!(path.into_inner(), std::path::PathBuf::from("a/b.txt"));
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> PathBuf {
+        self.0
+    }
+}
+
+impl Deref for RelativePath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for RelativePath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+fn is_safe_component(component: Component<'_>) -> bool {
+    match component {
+        Component::Normal(part) => part.to_str()
+            .map_or(false, |s| !s.contains(|c| c == '\\' || c == ':' || c == '\0')),
+        _ => false,
+    }
+}
+
+impl<'v> FromFormValue<'v> for RelativePath {
+    type Error = &'v RawStr;
+
+    fn from_form_value(v: &'v RawStr) -> Result<Self, Self::Error> {
+        let decoded = v.url_decode().map_err(|_| v)?;
+        let path = Path::new(&decoded);
+        if decoded.is_empty() || !path.components().all(is_safe_component) {
+            return Err(v);
+        }
+
+        Ok(RelativePath(path.components().collect()))
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::str::FromStr;
+use std::net::{AddrParseError, IpAddr};
+
+/// An IPv4 or IPv6 subnet in CIDR notation, such as `10.0.0.0/8`.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::Subnet;
+///
+/// let subnet: Subnet = "10.0.0.0/8".parse().unwrap();
+/// assert!(subnet.contains(&"10.20.30.40".parse().unwrap()));
+/// assert!(!subnet.contains(&"192.168.1.1".parse().unwrap()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Subnet {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+/// Error returned when parsing a [`Subnet`] or validating an address with
+/// [`in_subnet()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubnetError {
+    /// The address, or the address part of a subnet, is invalid.
+    Address(AddrParseError),
+    /// The prefix length of a subnet is missing or too long for its address.
+    Prefix,
+    /// The address `.0` is not in the subnet `.1`.
+    Outside(IpAddr, Subnet),
+}
+
+impl Subnet {
+    /// Returns the address of the subnet, as written.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the length, in bits, of the subnet's prefix.
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// Returns `true` if `addr` is in the subnet. IPv4 addresses are never in
+    /// IPv6 subnets and vice versa.
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(net) & mask == u32::from(*addr) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(net) & mask == u128::from(*addr) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Subnet {
+    type Err = SubnetError;
+
+    fn from_str(string: &str) -> Result<Subnet, SubnetError> {
+        let (addr, prefix) = match string.find('/') {
+            Some(i) => (&string[..i], &string[(i + 1)..]),
+            None => return Err(SubnetError::Prefix),
+        };
+
+        let addr: IpAddr = addr.parse().map_err(SubnetError::Address)?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        match prefix.parse::<u8>() {
+            Ok(prefix) if prefix <= max => Ok(Subnet { addr, prefix }),
+            _ => Err(SubnetError::Prefix),
+        }
+    }
+}
+
+impl fmt::Display for Subnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+impl fmt::Display for SubnetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubnetError::Address(e) => write!(f, "invalid address: {}", e),
+            SubnetError::Prefix => write!(f, "invalid subnet prefix length"),
+            SubnetError::Outside(addr, subnet) => write!(f, "{} is not in {}", addr, subnet),
+        }
+    }
+}
+
+impl std::error::Error for SubnetError { }
+
+/// Returns a parser for IP addresses in the subnet `cidr`, for use with the
+/// `parse_with` field option of the [`FromForm`](crate::request::FromForm)
+/// derive.
+///
+/// The parser accepts the URL-decoded value of the field if it is an IP
+/// address in `cidr`. Otherwise, it returns a [`SubnetError`] describing why
+/// the address was rejected, and the form fails to parse with a
+/// [`FormParseError::BadValue`](crate::request::FormParseError::BadValue).
+/// `cidr` is parsed once, by this function, not for every value.
+///
+/// # Panics
+///
+/// Panics if `cidr` is not a valid [`Subnet`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::net::IpAddr;
+/// use rocket::request::in_subnet;
+///
+/// #[derive(FromForm)]
+/// struct Node {
+///     #[form(parse_with = r#"in_subnet("10.0.0.0/8")"#)]
+///     addr: IpAddr,
+/// }
+///
+/// let parse = in_subnet("10.0.0.0/8");
+/// assert!(parse("10.1.2.3").is_ok());
+/// assert_eq!(parse("192.168.1.1").unwrap_err().to_string(),
+///     "192.168.1.1 is not in 10.0.0.0/8");
+/// ```
+pub fn in_subnet(cidr: &str) -> impl Fn(&str) -> Result<IpAddr, SubnetError> {
+    let subnet: Subnet = cidr.parse()
+        .unwrap_or_else(|e| panic!("invalid subnet {:?}: {}", cidr, e));
+
+    move |value| {
+        let addr: IpAddr = value.parse().map_err(SubnetError::Address)?;
+        match subnet.contains(&addr) {
+            true => Ok(addr),
+            false => Err(SubnetError::Outside(addr, subnet)),
+        }
+    }
+}
@@ -153,6 +153,23 @@ impl<S, E> IntoOutcome<S, (Status, E), ()> for Result<S, E> {
 ///     Extracts the [`ContentType`] from the incoming request. If the request
 ///     didn't specify a Content-Type, the request is forwarded.
 ///
+///   * **AcceptsEncoding**
+///
+///     Parses the request's `Accept-Encoding` headers into an
+///     [`AcceptsEncoding`](crate::request::AcceptsEncoding), which lists the
+///     acceptable content-codings by priority.
+///
+///     _This implementation always returns successfully._
+///
+///   * **Conditional**
+///
+///     Parses the request's `If-Match`, `If-None-Match`, `If-Modified-Since`,
+///     and `If-Unmodified-Since` headers into a
+///     [`Conditional`](crate::request::Conditional), which evaluates the
+///     preconditions against the current state of a resource.
+///
+///     _This implementation always returns successfully._
+///
 ///   * **IpAddr**
 ///
 ///     Extracts the client ip address of the incoming request as an [`IpAddr`].
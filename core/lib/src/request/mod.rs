@@ -6,6 +6,10 @@ mod form;
 mod from_request;
 mod state;
 mod query;
+mod accepts_encoding;
+mod conditional;
+mod page;
+mod content_length;
 
 #[cfg(test)]
 mod tests;
@@ -15,11 +19,23 @@ mod tests;
 pub use self::request::Request;
 pub use self::from_request::{FromRequest, Outcome};
 pub use self::param::{FromParam, FromSegments};
+pub use self::param::{ExtPath, ExtPathError, Extensions};
+pub use self::param::{CheckedId, Checksum, Luhn};
 pub use self::form::{FromForm, FromFormValue};
-pub use self::form::{Form, LenientForm, FormItems, FormItem};
-pub use self::form::{FormError, FormParseError, FormDataError};
+pub use self::form::{Form, LenientForm, RawForm, FormItems, FormItem, RelativePath, Present};
+pub use self::form::{EmptyAsNone, FromFormattedValue};
+pub use self::form::{FormError, FormParseError, FormDataError, UnknownValue, RequiredFields};
+pub use self::form::OwnedFormParseError;
+pub use self::form::{FormMetrics, FormMetricsSink};
+pub use self::form::{Subnet, SubnetError, in_subnet};
+pub use self::form::{RangeError, in_range, one_of};
+#[cfg(feature = "regex")] pub use self::form::{PatternMismatch, matching};
 pub use self::state::State;
 pub use self::query::{Query, FromQuery};
+pub use self::accepts_encoding::AcceptsEncoding;
+pub use self::conditional::{Conditional, ETag, EntityTags};
+pub use self::page::{Page, Pagination, PageError};
+pub use self::content_length::{ContentLength, ContentLengthError};
 
 #[doc(inline)]
 pub use crate::response::flash::FlashMessage;
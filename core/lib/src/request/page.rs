@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::request::{self, FromRequest, Request};
+use crate::outcome::Outcome::*;
+use crate::http::Status;
+
+/// Request guard for the pagination parameters of a list endpoint.
+///
+/// `Page` reads the `page` and `per_page` query parameters of the request. The
+/// page number is 1-based and defaults to `1`. The number of items per page
+/// defaults to, and is bounded by, the [`Pagination`] configuration in managed
+/// state, if any, or [`Pagination::default()`] otherwise. The guard exposes the
+/// resulting [`offset()`](Page::offset()) and [`limit()`](Page::limit()) for
+/// use in a query.
+///
+/// Both parameters are parsed regardless of the route's declared query
+/// parameters, so routes need not declare them.
+///
+/// # Failure
+///
+/// If either parameter is not an unsigned integer, the guard fails with status
+/// `BadRequest` and a [`PageError::Invalid`]. If a parameter is out of range,
+/// the value is clamped to the nearest bound when [`Pagination::clamp()`] is
+/// set, the default. Otherwise, the guard fails with status `BadRequest` and a
+/// [`PageError::OutOfRange`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{Page, Pagination};
+///
+/// #[get("/items")]
+/// fn items(page: Page) -> String {
+///     format!("SELECT * FROM items LIMIT {} OFFSET {}", page.limit(), page.offset())
+/// }
+///
+/// #[launch]
+/// fn rocket() -> rocket::Rocket {
+///     rocket::ignite()
+///         .manage(Pagination::default().per_page(25).bounds(10, 50))
+///         .mount("/", routes![items])
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Page {
+    number: u64,
+    per_page: u64,
+}
+
+/// The configuration of the [`Page`] request guard.
+///
+/// Manage a `Pagination` to change the defaults, which are `20` items per page
+/// between bounds of `1` and `100`, with out-of-range values clamped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    per_page: u64,
+    min_per_page: u64,
+    max_per_page: u64,
+    clamp: bool,
+}
+
+/// Error returned by the [`Page`] request guard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageError {
+    /// The parameter named `.0` is not an unsigned integer.
+    Invalid(&'static str),
+    /// The value `.1` of the parameter named `.0` is out of range.
+    OutOfRange(&'static str, u64),
+}
+
+impl Page {
+    /// Returns the 1-based page number.
+    #[inline(always)]
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Returns the number of items per page.
+    #[inline(always)]
+    pub fn per_page(&self) -> u64 {
+        self.per_page
+    }
+
+    /// Returns the number of items preceding the page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::Request;
+    /// # use rocket::http::Method;
+    /// use rocket::request::{Page, Pagination};
+    ///
+    /// # Request::example(Method::Get, "/?page=3&per_page=10", |request| {
+    /// let page = Page::from_request_with(request, &Pagination::default()).unwrap();
+    /// assert_eq!(page.offset(), 20);
+    /// assert_eq!(page.limit(), 10);
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn offset(&self) -> u64 {
+        (self.number - 1).saturating_mul(self.per_page)
+    }
+
+    /// Returns the maximum number of items on the page: the number of items
+    /// per page.
+    #[inline(always)]
+    pub fn limit(&self) -> u64 {
+        self.per_page
+    }
+
+    /// Parses the pagination parameters of `request` according to `config`.
+    pub fn from_request_with(
+        request: &Request<'_>,
+        config: &Pagination
+    ) -> Result<Page, PageError> {
+        let param = |name: &'static str| match request.get_query_value::<u64>(name) {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(_)) => Err(PageError::Invalid(name)),
+            None => Ok(None),
+        };
+
+        let bound = |name: &'static str, value: u64, min: u64, max: u64| match value {
+            v if v >= min && v <= max => Ok(v),
+            v if config.clamp => Ok(v.max(min).min(max)),
+            v => Err(PageError::OutOfRange(name, v)),
+        };
+
+        let number = match param("page")? {
+            Some(number) => bound("page", number, 1, u64::MAX)?,
+            None => 1,
+        };
+
+        let (min, max) = (config.min_per_page, config.max_per_page);
+        let per_page = match param("per_page")? {
+            Some(per_page) => bound("per_page", per_page, min, max)?,
+            None => config.per_page,
+        };
+
+        Ok(Page { number, per_page })
+    }
+}
+
+impl Pagination {
+    /// Sets the number of items per page when `per_page` is absent. The value
+    /// is clamped to the configured bounds.
+    pub fn per_page(mut self, per_page: u64) -> Self {
+        self.per_page = per_page.max(self.min_per_page).min(self.max_per_page);
+        self
+    }
+
+    /// Sets the inclusive bounds of `per_page`. The default number of items
+    /// per page is clamped to the new bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is `0` or `min > max`.
+    pub fn bounds(mut self, min: u64, max: u64) -> Self {
+        assert!(min > 0 && min <= max, "invalid per-page bounds: [{}, {}]", min, max);
+        self.min_per_page = min;
+        self.max_per_page = max;
+        self.per_page(self.per_page)
+    }
+
+    /// Sets whether out-of-range values are clamped to the nearest bound
+    /// (`true`) or rejected (`false`).
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination { per_page: 20, min_per_page: 1, max_per_page: 100, clamp: true }
+    }
+}
+
+impl fmt::Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PageError::Invalid(name) => write!(f, "`{}` must be an unsigned integer", name),
+            PageError::OutOfRange(name, v) => write!(f, "`{}` is out of range: {}", name, v),
+        }
+    }
+}
+
+impl std::error::Error for PageError { }
+
+#[crate::async_trait]
+impl<'a, 'r> FromRequest<'a, 'r> for Page {
+    type Error = PageError;
+
+    async fn from_request(request: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let default = Pagination::default();
+        let config = request.managed_state::<Pagination>().unwrap_or(&default);
+        match Page::from_request_with(request, config) {
+            Ok(page) => Success(page),
+            Err(e) => {
+                error_!("Invalid pagination: {}.", e);
+                Failure((Status::BadRequest, e))
+            }
+        }
+    }
+}
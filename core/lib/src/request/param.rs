@@ -1,7 +1,9 @@
 use std::str::FromStr;
-use std::path::PathBuf;
-use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::fmt::{self, Debug};
 use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::ops::Deref;
 
 use crate::http::{RawStr, uri::{Segments, SegmentError}};
 
@@ -83,6 +85,12 @@ use crate::http::{RawStr, uri::{Segments, SegmentError}};
 ///     type returns successfully. Otherwise, the raw path segment is returned
 ///     in the `Err` value.
 ///
+///   * **Uuid** _from `uuid`, with the `uuid` feature_
+///
+///     A value is parsed successfully if the path segment is a UUID in its
+///     canonical hyphenated form, as in `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///     Otherwise, the raw path segment is returned in the `Err` value.
+///
 ///   * **[`&RawStr`](RawStr)**
 ///
 ///     _This implementation always returns successfully._
@@ -254,6 +262,18 @@ impl_with_fromstr! {
     bool, IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr
 }
 
+/// Parses a UUID in the same way as its `FromFormValue` implementation.
+#[cfg(feature = "uuid")]
+#[cfg_attr(nightly, doc(cfg(feature = "uuid")))]
+impl<'a> FromParam<'a> for uuid::Uuid {
+    type Error = &'a RawStr;
+
+    #[inline(always)]
+    fn from_param(param: &'a RawStr) -> Result<Self, Self::Error> {
+        <uuid::Uuid as crate::request::FromFormValue>::from_form_value(param)
+    }
+}
+
 impl<'a, T: FromParam<'a>> FromParam<'a> for Result<T, T::Error> {
     type Error = std::convert::Infallible;
 
@@ -361,3 +381,266 @@ impl<'a, T: FromSegments<'a>> FromSegments<'a> for Option<T> {
         }
     }
 }
+
+/// A set of file extensions permitted by an [`ExtPath`].
+///
+/// Implement this trait on a marker type to declare the extensions, without a
+/// leading `.`, that an [`ExtPath`] should accept. Extensions are compared
+/// case-insensitively.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::Extensions;
+///
+/// struct Documents;
+///
+/// impl Extensions for Documents {
+///     const ALLOWED: &'static [&'static str] = &["pdf", "txt"];
+/// }
+/// ```
+pub trait Extensions {
+    /// The permitted file extensions.
+    const ALLOWED: &'static [&'static str];
+}
+
+/// A path whose final component has one of the extensions in `E`.
+///
+/// `ExtPath` implements both [`FromSegments`] and [`FromParam`]. It parses
+/// exactly as [`PathBuf`] does, with the same security checks, and then
+/// additionally requires that the final component of the path has an extension
+/// listed in [`E::ALLOWED`](Extensions::ALLOWED). This makes it well suited to
+/// guarding download endpoints.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{ExtPath, Extensions};
+///
+/// struct Documents;
+///
+/// impl Extensions for Documents {
+///     const ALLOWED: &'static [&'static str] = &["pdf", "txt"];
+/// }
+///
+/// #[get("/download/<path..>")]
+/// fn download(path: ExtPath<Documents>) -> String {
+///     format!("Downloading {}", path.display())
+/// }
+/// # fn main() { }
+/// ```
+///
+/// A request to `/download/reports/q1.pdf` is routed to `download`, while a
+/// request to `/download/reports/q1.exe` is forwarded.
+pub struct ExtPath<E> {
+    path: PathBuf,
+    _extensions: PhantomData<fn() -> E>,
+}
+
+/// Error returned by the [`FromSegments`] and [`FromParam`] implementations of
+/// [`ExtPath`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ExtPathError {
+    /// The path failed to parse. See the [`FromSegments`] implementation for
+    /// [`PathBuf`] for details.
+    Segment(SegmentError),
+    /// The path's final component has no extension or an extension that isn't
+    /// permitted. The parsed path is in `.0`.
+    Extension(PathBuf),
+}
+
+impl<E> ExtPath<E> {
+    /// Consumes `self` and returns the inner `PathBuf`.
+    #[inline(always)]
+    pub fn into_inner(self) -> PathBuf {
+        self.path
+    }
+}
+
+impl<E: Extensions> ExtPath<E> {
+    fn from_path(path: PathBuf) -> Result<Self, ExtPathError> {
+        let allowed = path.extension()
+            .and_then(|ext| ext.to_str())
+            .map_or(false, |ext| E::ALLOWED.iter().any(|a| a.eq_ignore_ascii_case(ext)));
+
+        match allowed {
+            true => Ok(ExtPath { path, _extensions: PhantomData }),
+            false => Err(ExtPathError::Extension(path)),
+        }
+    }
+}
+
+impl<E> fmt::Debug for ExtPath<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path.fmt(f)
+    }
+}
+
+impl<E> Deref for ExtPath<E> {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<E> AsRef<Path> for ExtPath<E> {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<E: Extensions> FromSegments<'_> for ExtPath<E> {
+    type Error = ExtPathError;
+
+    fn from_segments(segments: Segments<'_>) -> Result<Self, Self::Error> {
+        let path = PathBuf::from_segments(segments).map_err(ExtPathError::Segment)?;
+        ExtPath::from_path(path)
+    }
+}
+
+impl<'a, E: Extensions> FromParam<'a> for ExtPath<E> {
+    type Error = ExtPathError;
+
+    fn from_param(param: &'a RawStr) -> Result<Self, Self::Error> {
+        ExtPath::from_segments(Segments(param.as_str()))
+    }
+}
+
+/// A checksum scheme used to validate a [`CheckedId`].
+///
+/// Implement this trait on a marker type to declare how the checksum embedded
+/// in an ID is verified. [`Luhn`] is provided for numeric IDs.
+///
+/// # Example
+///
+/// IDs of the form `cus_<hex>` whose final hex digit is the sum of the others,
+/// modulo 16:
+///
+/// ```rust
+/// use rocket::request::Checksum;
+///
+/// struct CustomerId;
+///
+/// impl Checksum for CustomerId {
+///     fn verify(id: &str) -> bool {
+///         let digits: Option<Vec<u32>> = match id.strip_prefix("cus_") {
+///             Some(hex) => hex.chars().map(|c| c.to_digit(16)).collect(),
+///             None => return false,
+///         };
+///
+///         match digits.as_ref().and_then(|d| d.split_last()) {
+///             Some((check, rest)) => rest.iter().sum::<u32>() % 16 == *check,
+///             None => false,
+///         }
+///     }
+/// }
+/// ```
+pub trait Checksum {
+    /// Returns `true` if `id` is well-formed and its checksum is valid.
+    fn verify(id: &str) -> bool;
+}
+
+/// The [Luhn](https://en.wikipedia.org/wiki/Luhn_algorithm) checksum, used by
+/// credit card and many other identification numbers.
+///
+/// An ID is valid if it consists of at least two ASCII digits, the last of
+/// which is the Luhn check digit of the others.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::{Checksum, Luhn};
+///
+/// assert!(Luhn::verify("79927398713"));
+/// assert!(!Luhn::verify("79927398710"));
+/// ```
+#[derive(Debug)]
+pub struct Luhn;
+
+impl Checksum for Luhn {
+    fn verify(id: &str) -> bool {
+        if id.len() < 2 || !id.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+
+        let sum: u32 = id.bytes().rev().enumerate().map(|(i, b)| {
+            let digit = (b - b'0') as u32;
+            match i % 2 {
+                0 => digit,
+                _ if digit * 2 > 9 => digit * 2 - 9,
+                _ => digit * 2,
+            }
+        }).sum();
+
+        sum % 10 == 0
+    }
+}
+
+/// An ID path parameter with an embedded checksum verified by `C`.
+///
+/// `CheckedId` implements [`FromParam`]. The raw path segment is passed to
+/// [`C::verify()`](Checksum::verify): if it returns `true`, the `CheckedId`
+/// borrows the segment; otherwise, the raw segment is returned as the `Err`
+/// value. Rejecting malformed IDs this way avoids pointless lookups of IDs that
+/// cannot exist.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::{CheckedId, Luhn};
+///
+/// #[get("/cards/<number>")]
+/// fn card(number: CheckedId<Luhn>) -> String {
+///     format!("Card {}", number)
+/// }
+/// # fn main() { }
+/// ```
+///
+/// A request to `/cards/79927398713` is routed to `card`, while a request to
+/// `/cards/79927398710` is forwarded.
+pub struct CheckedId<'a, C> {
+    id: &'a str,
+    _checksum: PhantomData<fn() -> C>,
+}
+
+impl<'a, C> CheckedId<'a, C> {
+    /// Returns the ID as a string slice.
+    #[inline(always)]
+    pub fn as_str(&self) -> &'a str {
+        self.id
+    }
+}
+
+impl<C> fmt::Debug for CheckedId<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id.fmt(f)
+    }
+}
+
+impl<C> fmt::Display for CheckedId<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.id.fmt(f)
+    }
+}
+
+impl<C> Deref for CheckedId<'_, C> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.id
+    }
+}
+
+impl<'a, C: Checksum> FromParam<'a> for CheckedId<'a, C> {
+    type Error = &'a RawStr;
+
+    fn from_param(param: &'a RawStr) -> Result<Self, Self::Error> {
+        match C::verify(param.as_str()) {
+            true => Ok(CheckedId { id: param.as_str(), _checksum: PhantomData }),
+            false => Err(param),
+        }
+    }
+}
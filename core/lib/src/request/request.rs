@@ -13,7 +13,7 @@ use crate::request::{FromParam, FromSegments, FromRequest, Outcome};
 use crate::request::{FromFormValue, FormItems, FormItem};
 
 use crate::{Rocket, Config, Shutdown, Route};
-use crate::http::{hyper, uri::{Origin, Segments}};
+use crate::http::{hyper, uri::{Origin, Segments}, route::Kind, ext::IntoOwned};
 use crate::http::{Method, Header, HeaderMap, uncased::UncasedStr};
 use crate::http::{RawStr, ContentType, Accept, MediaType, CookieJar, Cookie};
 use crate::http::private::{Indexed, SmallVec};
@@ -160,6 +160,31 @@ impl<'r> Request<'r> {
         &self.uri
     }
 
+    /// Returns the complete, normalized [`Origin`] URI of the request: its
+    /// path and query. This is the URI routing was performed against and is
+    /// suitable for redirecting a client back to the current page.
+    ///
+    /// Unlike [`Request::uri()`], which returns the URI exactly as it was
+    /// received, the path of the returned URI is normalized: empty segments are
+    /// removed, as they are during routing. The query is preserved verbatim.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::Request;
+    /// # use rocket::http::Method;
+    /// # Request::example(Method::Get, "//hello//there/?name=Bob", |request| {
+    /// let uri = request.full_uri();
+    /// assert_eq!(uri.path(), "/hello/there");
+    /// assert_eq!(uri.query(), Some("name=Bob"));
+    /// assert_eq!(uri.to_string(), "/hello/there?name=Bob");
+    /// # });
+    /// ```
+    #[inline]
+    pub fn full_uri(&self) -> Origin<'static> {
+        self.uri.clone().into_normalized().into_owned()
+    }
+
     /// Set the URI in `self` to `uri`.
     ///
     /// # Example
@@ -386,7 +411,9 @@ impl<'r> Request<'r> {
     }
 
     /// Returns the Content-Type header of `self`. If the header is not present,
-    /// returns `None`.
+    /// returns `None`, unless the request has been routed to a route that sets
+    /// `assume_format`, in which case the route's format is returned. Data
+    /// guards, like `Form`, thus treat the body as being of that format.
     ///
     /// # Example
     ///
@@ -402,9 +429,13 @@ impl<'r> Request<'r> {
     /// ```
     #[inline(always)]
     pub fn content_type(&self) -> Option<&ContentType> {
-        self.state.content_type.get_or_set(|| {
+        let declared = self.state.content_type.get_or_set(|| {
             self.headers().get_one("Content-Type").and_then(|v| v.parse().ok())
-        }).as_ref()
+        });
+
+        declared.as_ref().or_else(|| {
+            self.route().and_then(|route| route.metadata.assumed_content_type.as_ref())
+        })
     }
 
     /// Returns the Accept header of `self`. If the header is not present,
@@ -735,6 +766,76 @@ impl<'r> Request<'r> {
             .find(|item| item.key.as_str() == key)
             .map(|item| T::from_form_value(item.value))
     }
+
+    /// Returns the names and raw, undecoded values of the dynamic path and
+    /// query parameters of the route that matched this request, in route
+    /// declaration order. Returns an empty vector if no route has matched.
+    ///
+    /// The value of a trailing `<param..>` path parameter is the remainder of
+    /// the path. The value of a `<param..>` query parameter is the entire
+    /// query string. Ignored path parameters (`<_>`) and query parameters that
+    /// are missing from the request are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{self, Request, FromRequest};
+    ///
+    /// struct LogParams;
+    ///
+    /// #[rocket::async_trait]
+    /// impl<'a, 'r> FromRequest<'a, 'r> for LogParams {
+    ///     type Error = std::convert::Infallible;
+    ///
+    ///     async fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+    ///         for (name, value) in req.matched_params() {
+    ///             println!("{} = {}", name, value);
+    ///         }
+    ///
+    ///         request::Outcome::Success(LogParams)
+    ///     }
+    /// }
+    /// ```
+    pub fn matched_params(&self) -> Vec<(&str, &RawStr)> {
+        let route = match self.route() {
+            Some(route) => route,
+            None => return vec![],
+        };
+
+        let path = self.uri.path();
+        let mut params = vec![];
+        for (i, segment) in route.metadata.path_segments.iter().enumerate() {
+            if segment.kind == Kind::Static || segment.name == "_" {
+                continue;
+            }
+
+            let value = match (segment.kind, self.state.path_segments.get(i)) {
+                (Kind::Multi, Some(&(start, _))) => &path[start..],
+                (Kind::Multi, None) => "",
+                (_, Some(&(start, end))) => &path[start..end],
+                (_, None) => continue,
+            };
+
+            params.push((&*segment.name, value.into()));
+        }
+
+        for segment in route.metadata.query_segments.iter().flatten() {
+            let value = match segment.kind {
+                Kind::Static => continue,
+                Kind::Single => {
+                    let value = self.get_query_value::<&RawStr>(&segment.name);
+                    value.and_then(Result::ok)
+                }
+                Kind::Multi => self.uri.query().map(RawStr::from_str),
+            };
+
+            if let Some(value) = value {
+                params.push((&*segment.name, value));
+            }
+        }
+
+        params
+    }
 }
 
 // All of these methods only exist for internal, including codegen, purposes.
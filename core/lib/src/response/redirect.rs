@@ -2,7 +2,7 @@ use std::convert::TryInto;
 
 use crate::request::Request;
 use crate::response::{self, Response, Responder};
-use crate::http::uri::Uri;
+use crate::http::uri::{Uri, Origin};
 use crate::http::Status;
 
 /// An empty redirect response to a given URL.
@@ -40,6 +40,30 @@ use crate::http::Status;
 /// }
 /// ```
 ///
+/// Routes mounted at a base other than `/` must include the mount point in the
+/// `uri!` invocation. Prefer [`Redirect::to_route()`] when redirecting to a
+/// route; it only accepts an [`Origin`] and so cannot be passed an arbitrary
+/// string by mistake:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::response::Redirect;
+///
+/// #[get("/")]
+/// fn index() -> &'static str {
+///     "Hello!"
+/// }
+///
+/// #[get("/old")]
+/// fn old() -> Redirect {
+///     Redirect::to_route(uri!("/app", index))
+/// }
+///
+/// # fn main() {
+/// rocket::ignite().mount("/app", routes![index, old]);
+/// # }
+/// ```
+///
 /// [`Origin`]: crate::http::uri::Origin
 /// [`uri!`]: ../macro.uri.html
 #[derive(Debug)]
@@ -64,6 +88,40 @@ impl Redirect {
         Redirect(Status::SeeOther, uri.try_into().ok())
     }
 
+    /// Construct a "see other" (303) redirect response to a route, given its
+    /// URI as generated by [`uri!`](../macro.uri.html).
+    ///
+    /// Because `uri!` resolves the route by name and typechecks its parameters
+    /// at compile-time, a typo in the route name or a change in its signature
+    /// results in a compile-time error rather than a broken redirect. Note
+    /// that `uri!` cannot know where the route is mounted: if the route is
+    /// mounted at a base other than `/`, the mount point must be supplied as
+    /// the first argument to `uri!`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::response::Redirect;
+    ///
+    /// #[get("/user/<id>")]
+    /// fn user(id: usize) -> String {
+    ///     format!("User #{}", id)
+    /// }
+    ///
+    /// #[get("/me")]
+    /// fn me() -> Redirect {
+    ///     Redirect::to_route(uri!("/api", user: 42))
+    /// }
+    ///
+    /// # fn main() {
+    /// rocket::ignite().mount("/api", routes![user, me]);
+    /// # }
+    /// ```
+    pub fn to_route(uri: Origin<'static>) -> Redirect {
+        Redirect(Status::SeeOther, Some(uri.into()))
+    }
+
    /// Construct a "temporary" (307) redirect response. This response instructs
    /// the client to reissue the current request to a different URL,
    /// maintaining the contents of the request identically. This means that,
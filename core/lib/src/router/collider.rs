@@ -32,6 +32,8 @@ impl Route {
     ///   * The route's format (if any) matches that of the incoming request.
     ///     - If route specifies format, it only gets requests for that format.
     ///     - If route doesn't specify format, it gets requests for any format.
+    ///     - If route assumes its format, it also gets payload requests
+    ///       without a `Content-Type`.
     ///   * All static components in the route's path match the corresponding
     ///     components in the same position in the incoming request.
     ///   * All static components in the route's query string are also in the
@@ -119,9 +121,12 @@ fn formats_collide(route: &Route, other: &Route) -> bool {
     // When matching against the `Content-Type` header, we'll only consider
     // requests as having a `Content-Type` if they're fully specified. If a
     // route doesn't have a `format`, it accepts all `Content-Type`s. If a
-    // request doesn't have a format, it only matches routes without a format.
+    // request doesn't have a format, it only matches routes without a format
+    // or routes that assume their format.
     match (route.format.as_ref(), other.format.as_ref()) {
-        (Some(a), Some(b)) => media_types_collide(a, b),
+        (Some(a), Some(b)) => {
+            media_types_collide(a, b) || (route.assume_format && other.assume_format)
+        }
         _ => true
     }
 }
@@ -136,6 +141,7 @@ fn formats_match(route: &Route, request: &Request<'_>) -> bool {
         match route.format.as_ref() {
             Some(a) => match request.format() {
                 Some(b) if b.specificity() == 2 => media_types_collide(a, b),
+                None => route.assume_format,
                 _ => false
             }
             None => true
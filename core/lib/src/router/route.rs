@@ -5,7 +5,7 @@ use yansi::Paint;
 
 use crate::codegen::StaticRouteInfo;
 use crate::handler::Handler;
-use crate::http::{Method, MediaType};
+use crate::http::{Method, MediaType, ContentType};
 use crate::http::route::{RouteSegment, Kind};
 use crate::error::RouteUriError;
 use crate::http::ext::IntoOwned;
@@ -31,6 +31,11 @@ pub struct Route {
     pub rank: isize,
     /// The media type this route matches against, if any.
     pub format: Option<MediaType>,
+    /// Whether a request for a payload-supporting method that lacks a
+    /// `Content-Type` is treated as being of `format`, both when routing and
+    /// by [`Request::content_type()`](crate::Request::content_type()) once
+    /// routed. Defaults to `false`.
+    pub assume_format: bool,
     /// Cached metadata that aids in routing later.
     pub(crate) metadata: Metadata,
 }
@@ -40,6 +45,8 @@ pub(crate) struct Metadata {
     pub path_segments: Vec<RouteSegment<'static, Path>>,
     pub query_segments: Option<Vec<RouteSegment<'static, Query>>>,
     pub fully_dynamic_query: bool,
+    /// The `Content-Type` of requests without one, if `assume_format` is set.
+    pub assumed_content_type: Option<ContentType>,
 }
 
 impl Metadata {
@@ -60,7 +67,14 @@ impl Metadata {
             None => (None, true)
         };
 
-        Ok(Metadata { path_segments, query_segments, fully_dynamic_query: is_dyn })
+        let assumed_content_type = match route.assume_format && route.method.supports_payload() {
+            true => route.format.clone().map(ContentType),
+            false => None,
+        };
+
+        Ok(Metadata {
+            path_segments, query_segments, fully_dynamic_query: is_dyn, assumed_content_type
+        })
     }
 }
 
@@ -180,6 +194,7 @@ impl Route {
             uri: route_path,
             name: None,
             format: None,
+            assume_format: false,
             base: Origin::dummy(),
             handler: Box::new(handler),
             metadata: Metadata::default(),
@@ -311,6 +326,7 @@ impl fmt::Debug for Route {
             .field("uri", &self.uri)
             .field("rank", &self.rank)
             .field("format", &self.format)
+            .field("assume_format", &self.assume_format)
             .field("metadata", &self.metadata)
             .finish()
     }
@@ -322,6 +338,7 @@ impl From<StaticRouteInfo> for Route {
         // This should never panic since `info.path` is statically checked.
         let mut route = Route::new(info.method, info.path, info.handler);
         route.format = info.format;
+        route.assume_format = info.assume_format;
         route.name = Some(info.name);
         if let Some(rank) = info.rank {
             route.rank = rank;
@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::AcceptsEncoding;
+
+#[get("/")]
+fn index(encodings: AcceptsEncoding) -> String {
+    let listed: Vec<_> = encodings.iter()
+        .map(|(coding, q)| format!("{}={}", coding, q))
+        .collect();
+
+    let preferred = encodings.preferred(&["br", "gzip", "identity"]);
+    format!("{} [{}]", preferred.unwrap_or("none"), listed.join(", "))
+}
+
+mod accepts_encoding_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::Header;
+
+    fn preference(values: &[&'static str]) -> String {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![super::index])).unwrap();
+        let mut request = client.get("/");
+        for value in values {
+            request.add_header(Header::new("Accept-Encoding", *value));
+        }
+
+        request.dispatch().into_string().unwrap()
+    }
+
+    #[test]
+    fn no_header_means_identity() {
+        assert_eq!(preference(&[]), "identity []");
+        assert_eq!(preference(&[""]), "identity []");
+    }
+
+    #[test]
+    fn codings_are_ordered_by_quality() {
+        assert_eq!(preference(&["gzip"]), "gzip [gzip=1]");
+        assert_eq!(preference(&["gzip, br"]), "br [gzip=1, br=1]");
+        assert_eq!(preference(&["gzip;q=1.0, br;q=0.5"]), "gzip [gzip=1, br=0.5]");
+        assert_eq!(preference(&["deflate;q=0.5", "GZIP ; q=0.8"]), "gzip [gzip=0.8, deflate=0.5]");
+        assert_eq!(preference(&["deflate"]), "identity [deflate=1]");
+    }
+
+    #[test]
+    fn wildcard_and_identity() {
+        assert_eq!(preference(&["*"]), "br [*=1]");
+        assert_eq!(preference(&["gzip;q=0.5, *;q=0.1"]), "gzip [gzip=0.5, *=0.1]");
+        assert_eq!(preference(&["identity;q=0"]), "none []");
+        assert_eq!(preference(&["*;q=0"]), "none []");
+        assert_eq!(preference(&["identity, *;q=0"]), "identity [identity=1]");
+        assert_eq!(preference(&["br;q=0, *"]), "gzip [*=1]");
+        assert_eq!(preference(&["deflate, identity;q=0"]), "none [deflate=1]");
+    }
+
+    #[test]
+    fn malformed_qualities_are_ignored() {
+        assert_eq!(preference(&["br;q=high, gzip;q=2, deflate;q=0.3"]), "identity [deflate=0.3]");
+        assert_eq!(preference(&["br;q=NaN, gzip;q=-1"]), "identity []");
+    }
+}
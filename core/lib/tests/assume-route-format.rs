@@ -0,0 +1,76 @@
+#[macro_use] extern crate rocket;
+
+#[post("/", data = "<body>", format = "json", assume_format = true)]
+fn json(body: String) -> String {
+    format!("json: {}", body)
+}
+
+#[post("/", data = "<_body>", rank = 2)]
+fn other(_body: rocket::Data) -> &'static str {
+    "other"
+}
+
+#[derive(FromForm)]
+struct Task {
+    description: String,
+}
+
+#[post("/form", data = "<task>", format = "form", assume_format = true)]
+fn form(task: rocket::request::Form<Task>) -> String {
+    format!("task: {}", task.description)
+}
+
+#[post("/strict", data = "<_body>", format = "json")]
+fn strict(_body: rocket::Data) -> &'static str {
+    "strict"
+}
+
+mod assume_format_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Status, ContentType};
+
+    fn client() -> Client {
+        let routes = routes![super::json, super::other, super::form, super::strict];
+        Client::tracked(rocket::ignite().mount("/", routes)).unwrap()
+    }
+
+    #[test]
+    fn missing_content_type_assumes_format() {
+        let client = client();
+        let response = client.post("/").body(r#"{ "a": 1 }"#).dispatch();
+        assert_eq!(response.into_string().unwrap(), r#"json: { "a": 1 }"#);
+    }
+
+    #[test]
+    fn data_guards_see_assumed_format() {
+        // `Form` only accepts form bodies; it sees the assumed content type.
+        let client = client();
+        let response = client.post("/form").body("description=Write+tests").dispatch();
+        assert_eq!(response.into_string().unwrap(), "task: Write tests");
+    }
+
+    #[test]
+    fn explicit_content_type_is_respected() {
+        let client = client();
+        let response = client.post("/")
+            .header(ContentType::JSON)
+            .body("[]")
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "json: []");
+
+        let response = client.post("/")
+            .header(ContentType::Plain)
+            .body("[]")
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "other");
+    }
+
+    #[test]
+    fn format_is_not_assumed_by_default() {
+        let client = client();
+        let response = client.post("/strict").body("[]").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
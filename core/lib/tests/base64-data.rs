@@ -0,0 +1,82 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::Base64;
+
+#[post("/", data = "<body>")]
+fn echo(body: Base64<String>) -> String {
+    body.into_inner()
+}
+
+mod base64_data_tests {
+    use rocket::local::blocking::Client;
+    use rocket::data::Limits;
+    use rocket::http::{Status, Header};
+
+    fn client() -> Client {
+        Client::tracked(rocket::ignite().mount("/", routes![super::echo])).unwrap()
+    }
+
+    fn client_with_limit(limit: u64) -> Client {
+        let limits = Limits::default().limit("base64", limit.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        Client::tracked(rocket::custom(config).mount("/", routes![super::echo])).unwrap()
+    }
+
+    #[test]
+    fn raw_body_passes_through() {
+        let client = client();
+        let response = client.post("/").body("Hello, world!").dispatch();
+        assert_eq!(response.into_string(), Some("Hello, world!".into()));
+    }
+
+    #[test]
+    fn base64_body_is_decoded() {
+        let client = client();
+        let response = client.post("/")
+            .header(Header::new("Content-Transfer-Encoding", "base64"))
+            .body("SGVsbG8sIHdvcmxkIQ==")
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("Hello, world!".into()));
+
+        let response = client.post("/")
+            .header(Header::new("Content-Transfer-Encoding", "BASE64"))
+            .body("SGVsbG8s\r\nIHdvcmxkIQ==\r\n")
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("Hello, world!".into()));
+    }
+
+    #[test]
+    fn invalid_base64_fails() {
+        let client = client();
+        let response = client.post("/")
+            .header(Header::new("Content-Transfer-Encoding", "base64"))
+            .body("SGVsbG8*")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn over_limit_body_fails() {
+        // 13 bytes decode from 20; 12 from 16. No `Content-Length` is sent, so
+        // the body must be rejected rather than truncated to a valid prefix.
+        let client = client_with_limit(12);
+        let response = client.post("/")
+            .header(Header::new("Content-Transfer-Encoding", "base64"))
+            .body("SGVsbG8sIHdvcmxkIQ==")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        // Whitespace doesn't count towards the limit.
+        let client = client_with_limit(13);
+        let response = client.post("/")
+            .header(Header::new("Content-Transfer-Encoding", "base64"))
+            .body("SGVs\r\nbG8s\r\nIHdv\r\ncmxk\r\nIQ==\r\n")
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("Hello, world!".into()));
+    }
+}
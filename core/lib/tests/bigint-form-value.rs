@@ -0,0 +1,41 @@
+#[cfg(feature = "bigint")]
+mod bigint_form_value {
+    use num_bigint::{BigInt, BigUint};
+    use rocket::request::FromFormValue;
+
+    fn int(value: &str) -> Result<String, &str> {
+        BigInt::from_form_value(value.into()).map(|n| n.to_string()).map_err(|e| e.as_str())
+    }
+
+    fn uint(value: &str) -> Result<String, &str> {
+        BigUint::from_form_value(value.into()).map(|n| n.to_string()).map_err(|e| e.as_str())
+    }
+
+    const HUGE: &str = "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+
+    #[test]
+    fn large_integers_parse() {
+        assert_eq!(uint(HUGE).unwrap(), HUGE);
+        assert_eq!(int(HUGE).unwrap(), HUGE);
+        assert_eq!(int(&format!("-{}", HUGE)).unwrap(), format!("-{}", HUGE));
+        assert_eq!(int(&format!("%2B{}", HUGE)).unwrap(), HUGE);
+
+        let hex = format!("0x1{}", "0".repeat(64));
+        assert_eq!(uint(&hex).unwrap(), HUGE);
+        assert_eq!(int(&format!("-{}", hex)).unwrap(), format!("-{}", HUGE));
+        assert_eq!(uint("0XfF").unwrap(), "255");
+        assert_eq!(uint("0").unwrap(), "0");
+        assert_eq!(int("-0").unwrap(), "0");
+    }
+
+    #[test]
+    fn invalid_integers_fail() {
+        for value in &["", "abc", "12a", "0x", "0xg1", "1.5", "--1", "0x-1", "0x%2B1", "%FF"] {
+            assert_eq!(int(value), Err(*value));
+            assert_eq!(uint(value), Err(*value));
+        }
+
+        assert_eq!(uint("-1"), Err("-1"));
+        assert_eq!(uint(&format!("-{}", HUGE)), Err(&*format!("-{}", HUGE)));
+    }
+}
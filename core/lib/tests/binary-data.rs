@@ -0,0 +1,92 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{Binary, DataStream, FromReader};
+use rocket::tokio::io::AsyncReadExt;
+
+/// A frame with a one-byte kind and a big-endian `u16` length-prefixed payload.
+struct Frame {
+    kind: u8,
+    payload: Vec<u8>,
+}
+
+#[rocket::async_trait]
+impl FromReader for Frame {
+    type Error = std::io::Error;
+
+    async fn from_reader(reader: &mut DataStream) -> Result<Self, Self::Error> {
+        let kind = reader.read_u8().await?;
+        let len = reader.read_u16().await?;
+        let mut payload = vec![0; len as usize];
+        reader.read_exact(&mut payload).await?;
+        Ok(Frame { kind, payload })
+    }
+}
+
+#[post("/", data = "<frame>")]
+fn frame(frame: Binary<Frame>) -> String {
+    format!("{}: {}", frame.kind, String::from_utf8_lossy(&frame.payload))
+}
+
+mod binary_data_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Status, ContentType};
+    use rocket::data::Limits;
+
+    fn client(limit: u64) -> Client {
+        let limits = Limits::default().limit("binary", limit.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        Client::tracked(rocket::custom(config).mount("/", routes![super::frame])).unwrap()
+    }
+
+    #[test]
+    fn length_prefixed_frame_is_parsed() {
+        let client = client(1024);
+        let response = client.post("/")
+            .header(ContentType::Binary)
+            .body(&b"\x07\x00\x05hello, trailing data is ignored"[..])
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("7: hello".into()));
+
+        let response = client.post("/")
+            .header(ContentType::Binary)
+            .body(&b"\x01\x00\x00"[..])
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("1: ".into()));
+    }
+
+    #[test]
+    fn truncated_frame_fails() {
+        let client = client(1024);
+        let response = client.post("/")
+            .header(ContentType::Binary)
+            .body(&b"\x07\x00\x05hell"[..])
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+
+        // Bodies the parser reads past the limit are rejected.
+        let client = client(6);
+        let response = client.post("/")
+            .header(ContentType::Binary)
+            .body(&b"\x07\x00\x05hello"[..])
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn non_binary_content_type_forwards() {
+        let client = client(1024);
+        let response = client.post("/").body(&b"\x07\x00\x05hello"[..]).dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let response = client.post("/")
+            .header(ContentType::Plain)
+            .body(&b"\x07\x00\x05hello"[..])
+            .dispatch();
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,70 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{CheckedId, Checksum, Luhn};
+use rocket::http::RawStr;
+
+/// IDs of the form `cus_<hex>` whose last digit is the sum of the others.
+struct CustomerId;
+
+impl Checksum for CustomerId {
+    fn verify(id: &str) -> bool {
+        let digits: Option<Vec<u32>> = match id.strip_prefix("cus_") {
+            Some(hex) => hex.chars().map(|c| c.to_digit(16)).collect(),
+            None => return false,
+        };
+
+        match digits.as_ref().and_then(|d| d.split_last()) {
+            Some((check, rest)) => rest.iter().sum::<u32>() % 16 == *check,
+            None => false,
+        }
+    }
+}
+
+#[get("/card/<number>")]
+fn card(number: CheckedId<Luhn>) -> String {
+    number.to_string()
+}
+
+#[get("/customer/<id>")]
+fn customer(id: Result<CheckedId<CustomerId>, &RawStr>) -> String {
+    match id {
+        Ok(id) => format!("valid: {}", id.as_str()),
+        Err(raw) => format!("invalid: {}", raw),
+    }
+}
+
+mod checked_id_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+
+    fn client() -> Client {
+        Client::tracked(rocket::ignite().mount("/", routes![super::card, super::customer])).unwrap()
+    }
+
+    #[test]
+    fn valid_ids_are_accepted() {
+        let client = client();
+        for number in &["79927398713", "4539578763621486", "00"] {
+            let response = client.get(format!("/card/{}", number)).dispatch();
+            assert_eq!(response.into_string().unwrap(), *number);
+        }
+
+        let response = client.get("/customer/cus_1234a").dispatch();
+        assert_eq!(response.into_string().unwrap(), "valid: cus_1234a");
+    }
+
+    #[test]
+    fn checksum_failures_are_rejected() {
+        let client = client();
+        for number in &["79927398710", "4539578763621487", "0", "7992739871x", "%37%39"] {
+            let response = client.get(format!("/card/{}", number)).dispatch();
+            assert_eq!(response.status(), Status::NotFound);
+        }
+
+        let response = client.get("/customer/cus_1234b").dispatch();
+        assert_eq!(response.into_string().unwrap(), "invalid: cus_1234b");
+
+        let response = client.get("/customer/cus_").dispatch();
+        assert_eq!(response.into_string().unwrap(), "invalid: cus_");
+    }
+}
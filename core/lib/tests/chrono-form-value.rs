@@ -0,0 +1,44 @@
+#[cfg(feature = "chrono")]
+mod chrono_form_value {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc, TimeZone};
+    use rocket::request::FromFormValue;
+
+    fn parse<'v, T: FromFormValue<'v>>(value: &'v str) -> Option<T> {
+        T::from_form_value(value.into()).ok()
+    }
+
+    #[test]
+    fn naive_dates() {
+        assert_eq!(parse("2021-03-14"), Some(NaiveDate::from_ymd(2021, 3, 14)));
+        assert_eq!(parse("0999-12-31"), Some(NaiveDate::from_ymd(999, 12, 31)));
+
+        for value in &["", "2021-02-30", "2021-3-14x", "14/03/2021", "2021-03-14T15:09"] {
+            assert_eq!(parse::<NaiveDate>(value), None, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn naive_date_times() {
+        let date = NaiveDate::from_ymd(2021, 3, 14);
+        assert_eq!(parse("2021-03-14T15:09"), Some(date.and_hms(15, 9, 0)));
+        assert_eq!(parse("2021-03-14T15%3A09%3A26"), Some(date.and_hms(15, 9, 26)));
+        assert_eq!(parse("2021-03-14T15:09:26.5"), Some(date.and_hms_milli(15, 9, 26, 500)));
+
+        let invalid = &["", "2021-03-14", "2021-03-14T25:00", "2021-03-14 15:09", "2021-03-14T15:09Z"];
+        for value in invalid {
+            assert_eq!(parse::<NaiveDateTime>(value), None, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn utc_date_times() {
+        let expected = Utc.ymd(2021, 3, 14).and_hms(14, 9, 26);
+        assert_eq!(parse("2021-03-14T15:09:26%2B01:00"), Some(expected));
+        assert_eq!(parse("2021-03-14T14:09:26Z"), Some(expected));
+        assert_eq!(parse("2021-03-14T09:09:26-05:00"), Some(expected));
+
+        for value in &["", "2021-03-14", "2021-03-14T14:09:26", "2021-03-14T14:09Z", "yesterday"] {
+            assert_eq!(parse::<DateTime<Utc>>(value), None, "{:?}", value);
+        }
+    }
+}
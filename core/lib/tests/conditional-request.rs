@@ -0,0 +1,146 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Conditional, ETag};
+use rocket::http::Status;
+
+/// The document was last modified on Sun, 06 Nov 1994 08:49:37 GMT.
+const LAST_MODIFIED: i64 = 784111777;
+
+fn evaluate(conditions: &Conditional) -> Option<Status> {
+    let last_modified = time::OffsetDateTime::from_unix_timestamp(LAST_MODIFIED);
+    conditions.evaluate(Some(&ETag::strong("v2")), Some(last_modified))
+}
+
+#[get("/doc")]
+fn get(conditions: Conditional) -> Result<&'static str, Status> {
+    evaluate(&conditions).map_or(Ok("document"), Err)
+}
+
+#[put("/doc", data = "<_doc>")]
+fn put(conditions: Conditional, _doc: String) -> Result<&'static str, Status> {
+    evaluate(&conditions).map_or(Ok("updated"), Err)
+}
+
+#[put("/missing", data = "<_doc>")]
+fn put_missing(conditions: Conditional, _doc: String) -> Result<&'static str, Status> {
+    conditions.evaluate(None, None).map_or(Ok("created"), Err)
+}
+
+mod conditional_request_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Header, Method, Status};
+
+    const BEFORE: &str = "Sat, 05 Nov 1994 08:49:37 GMT";
+    const AT: &str = "Sun, 06 Nov 1994 08:49:37 GMT";
+    const AFTER: &str = "Mon, 07 Nov 1994 08:49:37 GMT";
+
+    fn status(method: Method, uri: &str, headers: &[(&'static str, &'static str)]) -> Status {
+        let routes = routes![super::get, super::put, super::put_missing];
+        let client = Client::tracked(rocket::ignite().mount("/", routes)).unwrap();
+        let mut request = client.req(method, uri.to_string()).body("new");
+        for (name, value) in headers {
+            request.add_header(Header::new(*name, *value));
+        }
+
+        request.dispatch().status()
+    }
+
+    fn get(headers: &[(&'static str, &'static str)]) -> Status {
+        status(Method::Get, "/doc", headers)
+    }
+
+    fn put(headers: &[(&'static str, &'static str)]) -> Status {
+        status(Method::Put, "/doc", headers)
+    }
+
+    #[test]
+    fn unconditional_requests_proceed() {
+        assert_eq!(get(&[]), Status::Ok);
+        assert_eq!(put(&[]), Status::Ok);
+    }
+
+    #[test]
+    fn if_match() {
+        assert_eq!(put(&[("If-Match", "\"v2\"")]), Status::Ok);
+        assert_eq!(put(&[("If-Match", "\"v1\", \"v2\"")]), Status::Ok);
+        assert_eq!(put(&[("If-Match", "\"v1\""), ("If-Match", "\"v2\"")]), Status::Ok);
+        assert_eq!(put(&[("If-Match", "*")]), Status::Ok);
+        assert_eq!(put(&[("If-Match", "\"v1\"")]), Status::PreconditionFailed);
+        assert_eq!(put(&[("If-Match", "\"v1,v2\"")]), Status::PreconditionFailed);
+        assert_eq!(get(&[("If-Match", "\"v1\"")]), Status::PreconditionFailed);
+
+        // `If-Match` uses the strong comparison.
+        assert_eq!(put(&[("If-Match", "W/\"v2\"")]), Status::PreconditionFailed);
+
+        // Malformed tags never match.
+        assert_eq!(put(&[("If-Match", "v2")]), Status::PreconditionFailed);
+        assert_eq!(put(&[("If-Match", "v1, \"v2\"")]), Status::Ok);
+
+        // `*` only matches an existing resource.
+        assert_eq!(status(Method::Put, "/missing", &[("If-Match", "*")]),
+            Status::PreconditionFailed);
+    }
+
+    #[test]
+    fn if_none_match() {
+        assert_eq!(get(&[("If-None-Match", "\"v1\"")]), Status::Ok);
+        assert_eq!(get(&[("If-None-Match", "\"v2\"")]), Status::NotModified);
+        assert_eq!(get(&[("If-None-Match", "\"v1\", W/\"v2\"")]), Status::NotModified);
+        assert_eq!(get(&[("If-None-Match", "*")]), Status::NotModified);
+        assert_eq!(status(Method::Head, "/doc", &[("If-None-Match", "\"v2\"")]),
+            Status::NotModified);
+
+        // Only `GET` and `HEAD` requests are answered with `304`.
+        assert_eq!(put(&[("If-None-Match", "\"v1\"")]), Status::Ok);
+        assert_eq!(put(&[("If-None-Match", "\"v2\"")]), Status::PreconditionFailed);
+        assert_eq!(put(&[("If-None-Match", "*")]), Status::PreconditionFailed);
+        assert_eq!(status(Method::Put, "/missing", &[("If-None-Match", "*")]), Status::Ok);
+    }
+
+    #[test]
+    fn if_modified_since() {
+        assert_eq!(get(&[("If-Modified-Since", BEFORE)]), Status::Ok);
+        assert_eq!(get(&[("If-Modified-Since", AT)]), Status::NotModified);
+        assert_eq!(get(&[("If-Modified-Since", AFTER)]), Status::NotModified);
+
+        // Ignored for other methods and when the date is malformed.
+        assert_eq!(put(&[("If-Modified-Since", AT)]), Status::Ok);
+        assert_eq!(get(&[("If-Modified-Since", "yesterday")]), Status::Ok);
+        assert_eq!(get(&[("If-Modified-Since", "Sunday, 06-Nov-94 08:49:37 GMT")]), Status::Ok);
+    }
+
+    #[test]
+    fn if_unmodified_since() {
+        assert_eq!(put(&[("If-Unmodified-Since", AFTER)]), Status::Ok);
+        assert_eq!(put(&[("If-Unmodified-Since", AT)]), Status::Ok);
+        assert_eq!(put(&[("If-Unmodified-Since", BEFORE)]), Status::PreconditionFailed);
+        assert_eq!(get(&[("If-Unmodified-Since", BEFORE)]), Status::PreconditionFailed);
+        assert_eq!(put(&[("If-Unmodified-Since", "yesterday")]), Status::Ok);
+    }
+
+    #[test]
+    fn header_combinations() {
+        // `If-Match` takes precedence over `If-Unmodified-Since`.
+        assert_eq!(put(&[("If-Match", "\"v2\""), ("If-Unmodified-Since", BEFORE)]), Status::Ok);
+        assert_eq!(put(&[("If-Match", "\"v1\""), ("If-Unmodified-Since", AFTER)]),
+            Status::PreconditionFailed);
+
+        // `If-None-Match` takes precedence over `If-Modified-Since`.
+        assert_eq!(get(&[("If-None-Match", "\"v1\""), ("If-Modified-Since", AFTER)]), Status::Ok);
+        assert_eq!(get(&[("If-None-Match", "\"v2\""), ("If-Modified-Since", BEFORE)]),
+            Status::NotModified);
+
+        // A failed `If-Match` is reported before a matching `If-None-Match`.
+        assert_eq!(get(&[("If-Match", "\"v1\""), ("If-None-Match", "\"v2\"")]),
+            Status::PreconditionFailed);
+
+        // Both kinds of preconditions must pass.
+        assert_eq!(get(&[("If-Match", "\"v2\""), ("If-None-Match", "\"v2\"")]),
+            Status::NotModified);
+        assert_eq!(put(&[("If-Match", "\"v2\""), ("If-None-Match", "\"v1\"")]), Status::Ok);
+        assert_eq!(get(&[("If-Unmodified-Since", AFTER), ("If-Modified-Since", AT)]),
+            Status::NotModified);
+        assert_eq!(get(&[("If-Unmodified-Since", AT), ("If-Modified-Since", BEFORE)]),
+            Status::Ok);
+    }
+}
@@ -0,0 +1,61 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{ContentLength, ContentLengthError};
+
+#[post("/", data = "<body>")]
+fn length(length: Result<ContentLength, ContentLengthError>, body: String) -> String {
+    match length {
+        Ok(ContentLength(Some(length))) => format!("{} of {}", body.len(), length),
+        Ok(ContentLength(None)) => format!("{} of unknown", body.len()),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[post("/strict", data = "<_body>")]
+fn strict(length: ContentLength, _body: String) -> String {
+    format!("{:?}", *length)
+}
+
+mod content_length_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Header, Status};
+
+    fn client() -> Client {
+        Client::tracked(rocket::ignite().mount("/", routes![super::length, super::strict])).unwrap()
+    }
+
+    fn post(client: &Client, uri: &str, lengths: &[&'static str]) -> (Status, String) {
+        let mut request = client.post(uri.to_string()).body("hello");
+        for length in lengths {
+            request.add_header(Header::new("Content-Length", *length));
+        }
+
+        let response = request.dispatch();
+        (response.status(), response.into_string().unwrap_or_default())
+    }
+
+    #[test]
+    fn present_content_length() {
+        let client = client();
+        assert_eq!(post(&client, "/", &["5"]).1, "5 of 5");
+        assert_eq!(post(&client, "/", &[" 5 "]).1, "5 of 5");
+        assert_eq!(post(&client, "/", &["5", "5"]).1, "5 of 5");
+        assert_eq!(post(&client, "/strict", &["5"]), (Status::Ok, "Some(5)".into()));
+    }
+
+    #[test]
+    fn absent_content_length() {
+        let client = client();
+        assert_eq!(post(&client, "/", &[]).1, "5 of unknown");
+        assert_eq!(post(&client, "/strict", &[]), (Status::Ok, "None".into()));
+    }
+
+    #[test]
+    fn malformed_content_length() {
+        let client = client();
+        assert_eq!(post(&client, "/", &["five"]).1, "invalid Content-Length: \"five\"");
+        assert_eq!(post(&client, "/", &["-5"]).1, "invalid Content-Length: \"-5\"");
+        assert_eq!(post(&client, "/", &["5", "6"]).1, "invalid Content-Length: \"6\"");
+        assert_eq!(post(&client, "/strict", &["five"]).0, Status::BadRequest);
+    }
+}
@@ -0,0 +1,55 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::{Arc, Mutex};
+
+use rocket::data::{Data, ToByteUnit};
+use rocket::tokio::io::AsyncReadExt;
+
+#[post("/", data = "<data>")]
+async fn upload(data: Data) -> String {
+    let counts = Arc::new(Mutex::new(vec![]));
+    let reported = counts.clone();
+    let mut stream = data.open(1.mebibytes())
+        .on_progress(1000.bytes(), Some(4100), move |read, total| {
+            reported.lock().unwrap().push((read, total));
+        });
+
+    // Read in small chunks to force many reads.
+    let mut chunk = [0u8; 256];
+    let mut read = 0;
+    loop {
+        match stream.read(&mut chunk).await.unwrap() {
+            0 => break,
+            n => read += n,
+        }
+    }
+
+    let counts = counts.lock().unwrap();
+    let counts: Vec<_> = counts.iter()
+        .map(|(read, total)| format!("{}/{}", read, total.unwrap()))
+        .collect();
+
+    format!("{}: {}", read, counts.join(", "))
+}
+
+mod data_progress_tests {
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn progress_is_reported_in_intervals() {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![super::upload])).unwrap();
+        let response = client.post("/").body(vec![b'a'; 4100]).dispatch();
+        assert_eq!(response.into_string().unwrap(),
+            "4100: 1024/4100, 2048/4100, 3072/4100, 4096/4100, 4100/4100");
+    }
+
+    #[test]
+    fn progress_is_reported_for_small_bodies() {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![super::upload])).unwrap();
+        let response = client.post("/").body("hello").dispatch();
+        assert_eq!(response.into_string().unwrap(), "5: 5/4100");
+
+        let response = client.post("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "0: ");
+    }
+}
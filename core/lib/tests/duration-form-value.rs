@@ -0,0 +1,55 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::request::{Form, FromFormValue, OwnedFormParseError::BadValue};
+
+fn parse(value: &str) -> Result<Duration, &str> {
+    Duration::from_form_value(value.into()).map_err(|e| e.as_str())
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Cache {
+    ttl: Duration,
+    timeout: Option<Duration>,
+}
+
+#[test]
+fn durations() {
+    assert_eq!(parse("0"), Ok(Duration::from_secs(0)));
+    assert_eq!(parse("90"), Ok(Duration::from_secs(90)));
+    assert_eq!(parse("30s"), Ok(Duration::from_secs(30)));
+    assert_eq!(parse("250ms"), Ok(Duration::from_millis(250)));
+    assert_eq!(parse("2h30m"), Ok(Duration::from_secs(2 * 3600 + 30 * 60)));
+    assert_eq!(parse("1h1m1s1ms"), Ok(Duration::from_millis(3_661_001)));
+    assert_eq!(parse("90m"), Ok(Duration::from_secs(90 * 60)));
+    assert_eq!(parse("1m%3030s"), Ok(Duration::from_secs(90)));
+    assert_eq!(parse("1500ms"), Ok(Duration::from_millis(1500)));
+
+    let max = u64::MAX.to_string();
+    assert_eq!(parse(&max), Ok(Duration::from_secs(u64::MAX)));
+    assert_eq!(parse(&format!("{}s", max)), Ok(Duration::from_secs(u64::MAX)));
+}
+
+#[test]
+fn invalid_durations_fail() {
+    let values = &[
+        "", "s", "h30m", "-1", "+1", "+1s", "1.5s", "1d", "1H", "1sec", "2h 30m", "2h+30m",
+        "30m2h", "1s1s", "1ms1s", "1s1", "1m30", "%FF", "18446744073709551616",
+        "5124095576030432h", "1m18446744073709551615s",
+    ];
+
+    for value in values {
+        assert_eq!(parse(value), Err(*value), "{:?}", value);
+    }
+}
+
+#[test]
+fn duration_fields() {
+    let form = Form::<Cache>::parse_pairs(vec![("ttl", "2h30m"), ("timeout", "1500ms")]);
+    let (ttl, timeout) = (Duration::from_secs(9000), Some(Duration::from_millis(1500)));
+    assert_eq!(form, Ok(Cache { ttl, timeout }));
+
+    let form = Form::<Cache>::parse_pairs(vec![("ttl", "forever")]);
+    assert_eq!(form, Err(BadValue("ttl".into(), "forever".into())));
+}
@@ -0,0 +1,49 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, EmptyAsNone, OwnedFormParseError};
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Profile {
+    name: String,
+    nickname: EmptyAsNone<String>,
+    age: EmptyAsNone<u8>,
+    bio: Option<String>,
+}
+
+fn parse(pairs: &[(&str, &str)]) -> Result<Profile, OwnedFormParseError> {
+    Form::<Profile>::parse_pairs(pairs.iter().cloned())
+}
+
+fn profile(nickname: Option<&str>, age: Option<u8>, bio: Option<&str>) -> Profile {
+    Profile {
+        name: "Bob".into(),
+        nickname: EmptyAsNone(nickname.map(String::from)),
+        age: EmptyAsNone(age),
+        bio: bio.map(String::from),
+    }
+}
+
+#[test]
+fn empty_values_are_none() {
+    let form = parse(&[("name", "Bob"), ("nickname", ""), ("age", ""), ("bio", "")]);
+    assert_eq!(form, Ok(profile(None, None, Some(""))));
+}
+
+#[test]
+fn present_values_are_some() {
+    let form = parse(&[("name", "Bob"), ("nickname", "Bobby"), ("age", "42"), ("bio", "Hi")]);
+    assert_eq!(form, Ok(profile(Some("Bobby"), Some(42), Some("Hi"))));
+
+    // Whitespace is a value.
+    let form = parse(&[("name", "Bob"), ("nickname", " ")]);
+    assert_eq!(form, Ok(profile(Some(" "), None, None)));
+
+    // Unlike `Option`, values that fail to parse are errors.
+    let form = parse(&[("name", "Bob"), ("age", "old")]);
+    assert_eq!(form, Err(OwnedFormParseError::BadValue("age".into(), "old".into())));
+}
+
+#[test]
+fn absent_values_are_none() {
+    assert_eq!(parse(&[("name", "Bob")]), Ok(profile(None, None, None)));
+}
@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{ExtPath, Extensions};
+
+struct Documents;
+
+impl Extensions for Documents {
+    const ALLOWED: &'static [&'static str] = &["pdf", "txt"];
+}
+
+#[get("/download/<path..>")]
+fn download(path: ExtPath<Documents>) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+#[get("/download/<_path..>", rank = 2)]
+fn rejected(_path: std::path::PathBuf) -> &'static str {
+    "rejected"
+}
+
+#[get("/file/<name>")]
+fn file(name: ExtPath<Documents>) -> String {
+    name.to_string_lossy().into_owned()
+}
+
+mod ext_path_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+
+    fn client() -> Client {
+        let routes = routes![super::download, super::rejected, super::file];
+        Client::tracked(rocket::ignite().mount("/", routes)).unwrap()
+    }
+
+    #[test]
+    fn allowed_extensions_match() {
+        let client = client();
+        let response = client.get("/download/reports/q1.pdf").dispatch();
+        assert_eq!(response.into_string(), Some("reports/q1.pdf".into()));
+
+        let response = client.get("/download/notes.TXT").dispatch();
+        assert_eq!(response.into_string(), Some("notes.TXT".into()));
+
+        let response = client.get("/file/q1.pdf").dispatch();
+        assert_eq!(response.into_string(), Some("q1.pdf".into()));
+    }
+
+    #[test]
+    fn other_extensions_forward() {
+        let client = client();
+        let response = client.get("/download/reports/q1.exe").dispatch();
+        assert_eq!(response.into_string(), Some("rejected".into()));
+
+        let response = client.get("/download/reports/pdf").dispatch();
+        assert_eq!(response.into_string(), Some("rejected".into()));
+
+        let response = client.get("/file/q1.exe").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,40 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, FormError};
+
+#[derive(FromForm)]
+struct Person {
+    name: String,
+    #[form(field = "user-age")]
+    age: u8,
+}
+
+#[post("/", data = "<person>")]
+fn submit(person: Result<Form<Person>, FormError>) -> String {
+    match person {
+        Ok(person) => format!("{}, {}", person.name, person.age),
+        Err(e) => e.to_string(),
+    }
+}
+
+mod form_error_display_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::ContentType;
+
+    fn submit(body: &str) -> String {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![super::submit])).unwrap();
+        let response = client.post("/").header(ContentType::Form).body(body).dispatch();
+        response.into_string().unwrap()
+    }
+
+    #[test]
+    fn errors_display_readably() {
+        assert_eq!(submit("name=Bob&user-age=30"), "Bob, 30");
+        assert_eq!(submit("name=Bob&user-age=old+enough"),
+            "user-age: invalid value \"old enough\"");
+        assert_eq!(submit("name=Bob"), "user-age: missing");
+        assert_eq!(submit("name=Bob&user-age=30&is%20admin=true"),
+            "is admin: unexpected field with value \"true\"");
+        assert_eq!(submit("name=Bob&user-age=30&x=a=b"), "malformed form data");
+    }
+}
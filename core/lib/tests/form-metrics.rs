@@ -0,0 +1,116 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, LenientForm};
+
+#[derive(FromForm)]
+struct Login {
+    name: String,
+    password: String,
+}
+
+#[post("/strict", data = "<form>")]
+fn strict(form: Form<Login>) -> String {
+    form.into_inner().name
+}
+
+#[post("/lenient", data = "<form>")]
+fn lenient(form: LenientForm<Login>) -> String {
+    form.into_inner().name
+}
+
+mod form_metrics_tests {
+    use std::sync::{Arc, Mutex};
+
+    use rocket::local::blocking::Client;
+    use rocket::fairing::AdHoc;
+    use rocket::http::{ContentType, Header, Status};
+    use rocket::request::{FormMetrics, FormMetricsSink};
+
+    fn rocket() -> rocket::Rocket {
+        rocket::ignite()
+            .mount("/", routes![super::strict, super::lenient])
+            .attach(AdHoc::on_response("Metrics", |req, res| Box::pin(async move {
+                if let Some(metrics) = FormMetrics::of(req) {
+                    let value = format!("{}/{}", metrics.fields, metrics.bytes);
+                    res.set_header(Header::new("X-Form-Metrics", value));
+                }
+            })))
+    }
+
+    #[test]
+    fn metrics_are_recorded_when_enabled() {
+        let recorded = Arc::new(Mutex::new(vec![]));
+        let sink = recorded.clone();
+        let rocket = rocket().manage(FormMetricsSink::new(move |_, metrics| {
+            sink.lock().unwrap().push(*metrics);
+        }));
+
+        let client = Client::tracked(rocket).unwrap();
+        let response = client.post("/strict")
+            .header(ContentType::Form)
+            .body("name=Bob&password=hunter2")
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("X-Form-Metrics"), Some("2/25"));
+        assert_eq!(response.into_string().unwrap(), "Bob");
+
+        // Metrics are recorded for forms that fail to parse, too.
+        let response = client.post("/strict")
+            .header(ContentType::Form)
+            .body("name=Bob&password=hunter2&extra=1")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+
+        let response = client.post("/lenient")
+            .header(ContentType::Form)
+            .body("name=Al&password=x&extra=1")
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("X-Form-Metrics"), Some("3/26"));
+
+        let recorded = recorded.lock().unwrap();
+        let counts: Vec<_> = recorded.iter().map(|m| (m.fields, m.bytes)).collect();
+        assert_eq!(counts, vec![(2, 25), (3, 33), (3, 26)]);
+    }
+
+    #[test]
+    fn default_sink_only_caches_metrics() {
+        let client = Client::tracked(rocket().manage(FormMetricsSink::default())).unwrap();
+        let response = client.post("/strict")
+            .header(ContentType::Form)
+            .body("name=Bob&password=hunter2")
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("X-Form-Metrics"), Some("2/25"));
+    }
+
+    #[test]
+    fn metrics_can_be_read_before_parsing() {
+        let rocket = rocket()
+            .manage(FormMetricsSink::default())
+            .attach(AdHoc::on_request("Early", |req, _| Box::pin(async move {
+                assert!(FormMetrics::of(req).is_none());
+            })));
+
+        let client = Client::tracked(rocket).unwrap();
+        let response = client.post("/strict")
+            .header(ContentType::Form)
+            .body("name=Bob&password=hunter2")
+            .dispatch();
+
+        assert_eq!(response.headers().get_one("X-Form-Metrics"), Some("2/25"));
+    }
+
+    #[test]
+    fn metrics_are_not_recorded_when_disabled() {
+        let client = Client::tracked(rocket()).unwrap();
+        let response = client.post("/strict")
+            .header(ContentType::Form)
+            .body("name=Bob&password=hunter2")
+            .dispatch();
+
+        assert!(response.headers().get_one("X-Form-Metrics").is_none());
+        assert_eq!(response.into_string().unwrap(), "Bob");
+    }
+}
@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, OwnedFormParseError::*};
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Profile {
+    name: String,
+    age: u8,
+    #[form(field = "display name")]
+    display_name: Option<String>,
+    subscribed: bool,
+}
+
+mod form_parse_pairs_tests {
+    use super::*;
+
+    #[test]
+    fn pairs_build_a_struct() {
+        let pairs = vec![("name", "Ana María"), ("age", "30"), ("subscribed", "true")];
+        assert_eq!(Form::<Profile>::parse_pairs(pairs), Ok(Profile {
+            name: "Ana María".into(),
+            age: 30,
+            display_name: None,
+            subscribed: true,
+        }));
+    }
+
+    #[test]
+    fn values_are_not_decoded_again() {
+        let pairs = vec![
+            ("name", "a+b=c&d 100%"),
+            ("age", "7"),
+            ("display name", "%20"),
+            ("", ""),
+        ];
+
+        assert_eq!(Form::<Profile>::parse_pairs(pairs), Ok(Profile {
+            name: "a+b=c&d 100%".into(),
+            age: 7,
+            display_name: Some("%20".into()),
+            subscribed: false,
+        }));
+    }
+
+    #[test]
+    fn parsing_is_strict() {
+        let pairs = vec![("name", "Bob"), ("age", "ten")];
+        assert_eq!(Form::<Profile>::parse_pairs(pairs), Err(BadValue("age".into(), "ten".into())));
+
+        let pairs = vec![("name", "Bob"), ("age", "10"), ("admin", "true")];
+        let error = Unknown("admin".into(), "true".into());
+        assert_eq!(Form::<Profile>::parse_pairs(pairs), Err(error));
+
+        let pairs = vec![("age", "10")];
+        assert_eq!(Form::<Profile>::parse_pairs(pairs), Err(Missing("name".into())));
+
+        let error = Form::<Profile>::parse_pairs(vec![("name", "Bob"), ("age", "ten years")]);
+        assert_eq!(error.unwrap_err().to_string(), "age: invalid value \"ten years\"");
+    }
+}
@@ -0,0 +1,69 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, LenientForm, Present};
+
+#[derive(FromForm)]
+struct ProfilePatch {
+    name: Option<String>,
+    bio: Option<String>,
+    public: bool,
+}
+
+fn describe(patch: &Present<ProfilePatch>) -> String {
+    let mut fields: Vec<_> = patch.fields().iter().cloned().collect();
+    fields.sort();
+    format!("[{}] {:?} {:?} {}", fields.join(", "), patch.name, patch.bio, patch.public)
+}
+
+#[patch("/", data = "<patch>")]
+fn patch(patch: Form<Present<ProfilePatch>>) -> String {
+    describe(&patch)
+}
+
+#[patch("/lenient", data = "<patch>")]
+fn lenient(patch: LenientForm<Present<ProfilePatch>>) -> String {
+    describe(&patch)
+}
+
+mod form_present_fields_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+
+    fn patch(uri: &'static str, body: &str) -> (Status, Option<String>) {
+        let rocket = rocket::ignite().mount("/", routes![super::patch, super::lenient]);
+        let client = Client::tracked(rocket).unwrap();
+        let response = client.patch(uri).header(ContentType::Form).body(body).dispatch();
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn partial_submissions() {
+        let (status, body) = patch("/", "bio=Hello%21");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "[bio] None Some(\"Hello!\") false");
+
+        let (_, body) = patch("/", "public=false&name=Bob");
+        assert_eq!(body.unwrap(), "[name, public] Some(\"Bob\") None false");
+
+        let (_, body) = patch("/", "");
+        assert_eq!(body.unwrap(), "[] None None false");
+    }
+
+    #[test]
+    fn names_are_decoded_and_method_is_ignored() {
+        let (_, body) = patch("/", "name=Bob&_method=patch");
+        assert_eq!(body.unwrap(), "[name] Some(\"Bob\") None false");
+
+        let (_, body) = patch("/lenient", "bio=hi&x%20y=1");
+        assert_eq!(body.unwrap(), "[bio, x y] None Some(\"hi\") false");
+    }
+
+    #[test]
+    fn unknown_fields_are_recorded_when_lenient() {
+        let (status, _) = patch("/", "name=Bob&extra=1");
+        assert_eq!(status, Status::UnprocessableEntity);
+
+        let (_, body) = patch("/lenient", "name=Bob&extra=1");
+        assert_eq!(body.unwrap(), "[extra, name] Some(\"Bob\") None false");
+    }
+}
@@ -1,6 +1,6 @@
 #[macro_use] extern crate rocket;
 
-use rocket::request::Form;
+use rocket::request::{Form, FormError, FormDataError};
 
 #[derive(FromForm)]
 struct Simple {
@@ -12,16 +12,33 @@ fn index(form: Form<Simple>) -> String {
     form.into_inner().value
 }
 
+#[post("/truncate/<limit>", data = "<data>")]
+async fn truncate(limit: u64, data: rocket::Data) -> String {
+    match data.open(limit.into()).stream_to_capped_string().await.unwrap() {
+        (string, true) => string,
+        (string, false) => format!("{}...", string),
+    }
+}
+
+#[post("/check", data = "<form>")]
+fn check(form: Result<Form<Simple>, FormError<'_>>) -> String {
+    match form {
+        Ok(form) => form.into_inner().value,
+        Err(FormDataError::Incomplete(read, expected)) => format!("{}/{}", read, expected),
+        Err(e) => e.to_string(),
+    }
+}
+
 mod limits_tests {
     use rocket;
     use rocket::local::blocking::Client;
-    use rocket::http::{Status, ContentType};
+    use rocket::http::{Status, ContentType, Header};
     use rocket::data::Limits;
 
     fn rocket_with_forms_limit(limit: u64) -> rocket::Rocket {
         let limits = Limits::default().limit("forms", limit.into());
         let config = rocket::Config::figment().merge(("limits", limits));
-        rocket::custom(config).mount("/", routes![super::index])
+        rocket::custom(config).mount("/", routes![super::index, super::check, super::truncate])
     }
 
     #[test]
@@ -54,17 +71,130 @@ mod limits_tests {
             .header(ContentType::Form)
             .dispatch();
 
-        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn too_large_without_content_length() {
+        // Bodies over the limit are rejected, not truncated, even when no
+        // `Content-Length` is declared.
+        let client = Client::tracked(rocket_with_forms_limit(16)).unwrap();
+        let response = client.post("/")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        let response = client.post("/check")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("form data too large: 17 bytes".into()));
     }
 
     #[test]
-    fn contracted() {
-        let client = Client::tracked(rocket_with_forms_limit(10)).unwrap();
+    fn contracted_at_char_boundary() {
+        // The limit falls between the two bytes of `é`.
+        let client = Client::tracked(rocket_with_forms_limit(8)).unwrap();
+        let response = client.post("/truncate/8").body("value=héllo").dispatch();
+        assert_eq!(response.into_string(), Some("value=h...".into()));
+
+        let response = client.post("/truncate/9").body("value=héllo").dispatch();
+        assert_eq!(response.into_string(), Some("value=hé...".into()));
+
+        let response = client.post("/truncate/12").body("value=héllo").dispatch();
+        assert_eq!(response.into_string(), Some("value=héllo".into()));
+
+        // A complete body that ends mid-character is invalid, not truncated.
+        let response = client.post("/truncate/8").body(&b"value=h\xC3"[..]).dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected() {
+        let client = Client::tracked(rocket_with_forms_limit(128)).unwrap();
+        let response = client.post("/")
+            .body(&b"value=h\xC3"[..])
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::InternalServerError);
+    }
+
+    #[test]
+    fn content_length_too_large() {
+        let client = Client::tracked(rocket_with_forms_limit(16)).unwrap();
         let response = client.post("/")
             .body("value=Hello+world")
             .header(ContentType::Form)
+            .header(Header::new("Content-Length", "17"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn malformed_content_length() {
+        let client = Client::tracked(rocket_with_forms_limit(128)).unwrap();
+        let response = client.post("/")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "seventeen"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = client.post("/check")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "seventeen"))
+            .dispatch();
+
+        let expected = "invalid Content-Length: \"seventeen\"";
+        assert_eq!(response.into_string(), Some(expected.into()));
+    }
+
+    #[test]
+    fn content_length_within_limit() {
+        let client = Client::tracked(rocket_with_forms_limit(17)).unwrap();
+        let response = client.post("/")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "17"))
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("Hello world".into()));
+    }
+
+    #[test]
+    fn truncated_body_is_incomplete() {
+        let client = Client::tracked(rocket_with_forms_limit(128)).unwrap();
+        let response = client.post("/")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "30"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = client.post("/check")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "30"))
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("17/30".into()));
+
+        // A body that is invalid, but complete, still fails to parse.
+        let response = client.post("/check")
+            .body("other=Hello+world")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", "17"))
             .dispatch();
 
-        assert_eq!(response.into_string(), Some("Hell".into()));
+        let expected = "other: unexpected field with value \"Hello world\"";
+        assert_eq!(response.into_string(), Some(expected.into()));
     }
 }
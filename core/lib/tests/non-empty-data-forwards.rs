@@ -0,0 +1,61 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::Form;
+use rocket::data::NonEmpty;
+
+#[derive(FromForm)]
+struct Simple {
+    value: String
+}
+
+#[post("/", data = "<form>")]
+fn form(form: NonEmpty<Form<Simple>>) -> String {
+    form.into_inner().into_inner().value
+}
+
+#[post("/", rank = 2)]
+fn empty() -> &'static str {
+    "empty"
+}
+
+mod non_empty_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Status, ContentType};
+
+    fn client() -> Client {
+        let rocket = rocket::ignite().mount("/", routes![super::form, super::empty]);
+        Client::tracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn empty_body_forwards() {
+        let client = client();
+        let response = client.post("/")
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("empty".into()));
+    }
+
+    #[test]
+    fn non_empty_body_parses() {
+        let client = client();
+        let response = client.post("/")
+            .body("value=Hello+world")
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("Hello world".into()));
+    }
+
+    #[test]
+    fn invalid_body_fails() {
+        let client = client();
+        let response = client.post("/")
+            .body("other=Hello")
+            .header(ContentType::Form)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+}
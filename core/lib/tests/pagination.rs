@@ -0,0 +1,82 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Page, PageError};
+
+#[get("/items?<q>")]
+fn items(page: Result<Page, PageError>, q: Option<String>) -> String {
+    match page {
+        Ok(page) => format!("{} {} {} {:?}", page.number(), page.offset(), page.limit(), q),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[get("/strict")]
+fn strict(page: Page) -> String {
+    page.number().to_string()
+}
+
+mod pagination_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+    use rocket::request::Pagination;
+
+    fn client(pagination: Option<Pagination>) -> Client {
+        let mut rocket = rocket::ignite().mount("/", routes![super::items]);
+        if let Some(pagination) = pagination {
+            rocket = rocket.manage(pagination);
+        }
+
+        Client::tracked(rocket).unwrap()
+    }
+
+    fn get(client: &Client, uri: &str) -> String {
+        client.get(uri.to_string()).dispatch().into_string().unwrap()
+    }
+
+    #[test]
+    fn valid_pagination() {
+        let client = client(None);
+        assert_eq!(get(&client, "/items?page=1&per_page=10"), "1 0 10 None");
+        assert_eq!(get(&client, "/items?page=3&per_page=10"), "3 20 10 None");
+        assert_eq!(get(&client, "/items?per_page=5&q=rust&page=2"), "2 5 5 Some(\"rust\")");
+    }
+
+    #[test]
+    fn missing_pagination() {
+        let defaults = client(None);
+        assert_eq!(get(&defaults, "/items"), "1 0 20 None");
+        assert_eq!(get(&defaults, "/items?page=4"), "4 60 20 None");
+        assert_eq!(get(&defaults, "/items?per_page=50"), "1 0 50 None");
+
+        let custom = client(Some(Pagination::default().per_page(25).bounds(10, 50)));
+        assert_eq!(get(&custom, "/items?page=2"), "2 25 25 None");
+    }
+
+    #[test]
+    fn out_of_range_pagination_clamps() {
+        let client = client(Some(Pagination::default().bounds(10, 50)));
+        assert_eq!(get(&client, "/items?page=0"), "1 0 20 None");
+        assert_eq!(get(&client, "/items?page=2&per_page=1"), "2 10 10 None");
+        assert_eq!(get(&client, "/items?page=2&per_page=500"), "2 50 50 None");
+    }
+
+    #[test]
+    fn out_of_range_pagination_errors() {
+        let client = client(Some(Pagination::default().bounds(10, 50).clamp(false)));
+        assert_eq!(get(&client, "/items?page=0"), "`page` is out of range: 0");
+        assert_eq!(get(&client, "/items?per_page=500"), "`per_page` is out of range: 500");
+        assert_eq!(get(&client, "/items?per_page=50"), "1 0 50 None");
+    }
+
+    #[test]
+    fn invalid_pagination() {
+        let client = client(None);
+        assert_eq!(get(&client, "/items?page=two"), "`page` must be an unsigned integer");
+        assert_eq!(get(&client, "/items?per_page=-1"), "`per_page` must be an unsigned integer");
+
+        let rocket = rocket::ignite().mount("/", routes![super::strict]);
+        let client = Client::tracked(rocket).unwrap();
+        assert_eq!(client.get("/strict?page=2").dispatch().into_string().unwrap(), "2");
+        assert_eq!(client.get("/strict?page=x").dispatch().status(), Status::BadRequest);
+    }
+}
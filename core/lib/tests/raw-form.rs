@@ -0,0 +1,68 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::RawForm;
+
+#[derive(FromForm)]
+struct Event<'r> {
+    kind: String,
+    note: &'r rocket::http::RawStr,
+}
+
+#[post("/", data = "<event>")]
+fn webhook(event: RawForm<'_, Event<'_>>) -> String {
+    let raw = std::str::from_utf8(event.raw()).unwrap();
+    format!("{} ({}) from {:?}", event.kind, event.note.url_decode_lossy(), raw)
+}
+
+mod raw_form_tests {
+    use rocket::local::blocking::Client;
+    use rocket::data::Limits;
+    use rocket::http::{ContentType, Header, Status};
+
+    fn client() -> Client {
+        let limits = Limits::default().limit("forms", 64.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        Client::tracked(rocket::custom(config).mount("/", routes![super::webhook])).unwrap()
+    }
+
+    fn post(client: &Client, body: &str) -> (Status, Option<String>) {
+        let response = client.post("/").header(ContentType::Form).body(body).dispatch();
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn parses_and_retains_raw_body() {
+        let client = client();
+        let body = "kind=push&note=hello+w%6Frld%21";
+        let (status, string) = post(&client, body);
+        assert_eq!(status, Status::Ok);
+        assert_eq!(string.unwrap(), format!("push (hello world!) from {:?}", body));
+    }
+
+    #[test]
+    fn fails_like_form() {
+        let client = client();
+
+        // Parsing is strict.
+        let (status, _) = post(&client, "kind=push&note=hi&extra=1");
+        assert_eq!(status, Status::UnprocessableEntity);
+
+        // The body is limited by the `forms` limit.
+        let body = format!("kind=push&note={}", "a".repeat(64));
+        let response = client.post("/")
+            .header(ContentType::Form)
+            .header(Header::new("Content-Length", body.len().to_string()))
+            .body(body)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        // ...even when no `Content-Length` is declared.
+        let (status, _) = post(&client, &format!("kind=push&note={}", "a".repeat(64)));
+        assert_eq!(status, Status::PayloadTooLarge);
+
+        // Non-form requests are forwarded.
+        let response = client.post("/").body("kind=push&note=hi").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,34 @@
+#[macro_use] extern crate rocket;
+
+use rocket::response::Redirect;
+
+#[get("/user/<id>")]
+fn user(id: usize) -> String {
+    format!("User #{}", id)
+}
+
+#[get("/me")]
+fn me() -> Redirect {
+    Redirect::to_route(uri!("/api/v1", user: 42))
+}
+
+mod redirect_to_route_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+
+    #[test]
+    fn redirect_includes_mount_point() {
+        let rocket = rocket::ignite().mount("/api/v1", routes![user, me]);
+        let client = Client::tracked(rocket).unwrap();
+
+        let response = client.get("/api/v1/me").dispatch();
+        assert_eq!(response.status(), Status::SeeOther);
+
+        let location = response.headers().get_one("Location").unwrap().to_string();
+        assert_eq!(location, "/api/v1/user/42");
+
+        let response = client.get(location).dispatch();
+        assert_eq!(response.into_string().unwrap(), "User #42");
+    }
+}
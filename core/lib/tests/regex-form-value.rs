@@ -0,0 +1,79 @@
+#[cfg(feature = "regex")]
+#[macro_use] extern crate rocket;
+
+#[cfg(feature = "regex")]
+mod regex_form_value {
+    use rocket::request::{Form, FormItems, FromForm, OwnedFormParseError, matching};
+    use rocket::request::OwnedFormParseError::BadValue;
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Signup {
+        #[form(parse_with = r#"rocket::request::matching(r"^[a-z0-9_]+$")"#)]
+        username: String,
+        #[form(parse_with = r#"matching("[0-9]")"#)]
+        password: String,
+    }
+
+    fn signup(username: &str, password: &str) -> Result<Signup, OwnedFormParseError> {
+        Form::<Signup>::parse_pairs(vec![("username", username), ("password", password)])
+    }
+
+    #[test]
+    fn matching_values_parse() {
+        let form = signup("bob_99", "hunter2");
+        assert_eq!(form, Ok(Signup { username: "bob_99".into(), password: "hunter2".into() }));
+
+        // Patterns match the decoded value.
+        let mut items = FormItems::from("username=bob%5F99&password=hunter%32");
+        let form = Signup::from_form(&mut items, true);
+        assert_eq!(form, Ok(Signup { username: "bob_99".into(), password: "hunter2".into() }));
+    }
+
+    #[test]
+    fn mismatched_values_fail() {
+        assert_eq!(signup("Bob", "hunter2"), Err(BadValue("username".into(), "Bob".into())));
+        assert_eq!(signup("", "hunter2"), Err(BadValue("username".into(), "".into())));
+        assert_eq!(signup("bob-99", "hunter2"), Err(BadValue("username".into(), "bob-99".into())));
+        assert_eq!(signup("bob", "hunter"), Err(BadValue("password".into(), "hunter".into())));
+
+        let error = matching(r"^[a-z]+$")("Bob").unwrap_err();
+        assert_eq!(error.to_string(), "\"Bob\" does not match the pattern `^[a-z]+$`");
+    }
+
+    #[test]
+    fn unanchored_patterns_match_anywhere() {
+        let parse = matching("[0-9]");
+        assert!(parse("a1b").is_ok());
+        assert!(parse("abc").is_err());
+
+        let parse = matching("^[0-9]+$");
+        assert!(parse("a1b").is_err());
+        assert!(parse("123").is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid pattern \"[a-z\"")]
+    fn invalid_patterns_panic_on_construction() {
+        // The pattern is compiled, and rejected, before any value is parsed.
+        let _ = matching("[a-z");
+    }
+
+    mod words {
+        /// A parser unrelated to Rocket's that happens to be named `matching`.
+        pub fn matching(word: &'static str) -> impl Fn(&str) -> Result<String, ()> {
+            move |value| if value == word { Ok(value.into()) } else { Err(()) }
+        }
+    }
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Bracketed {
+        #[form(parse_with = r#"words::matching("[a-z")"#)]
+        word: String,
+    }
+
+    #[test]
+    fn other_matching_functions_are_not_checked() {
+        let form = Form::<Bracketed>::parse_pairs(vec![("word", "[a-z")]);
+        assert_eq!(form, Ok(Bracketed { word: "[a-z".into() }));
+    }
+}
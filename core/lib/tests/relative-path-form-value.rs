@@ -0,0 +1,28 @@
+use std::path::PathBuf;
+
+use rocket::request::{FromFormValue, RelativePath};
+
+fn parse(value: &str) -> Result<PathBuf, &str> {
+    RelativePath::from_form_value(value.into())
+        .map(|path| path.into_inner())
+        .map_err(|e| e.as_str())
+}
+
+#[test]
+fn safe_relative_paths_parse() {
+    assert_eq!(parse("a.txt"), Ok(PathBuf::from("a.txt")));
+    assert_eq!(parse("users%2F42%2Favatar.png"), Ok(PathBuf::from("users/42/avatar.png")));
+    assert_eq!(parse("users/42/"), Ok(PathBuf::from("users/42")));
+    assert_eq!(parse("users//42"), Ok(PathBuf::from("users/42")));
+}
+
+#[test]
+fn traversal_attempts_fail() {
+    for value in &[
+        "", "..", "../etc/passwd", "a/../../b", "a%2F..%2F..%2Fb", "./a",
+        "/etc/passwd", "%2Fetc%2Fpasswd", "C:", "C:%5Cwindows", "%5C%5Cserver%5Cshare",
+        "a%5C..%5Cb", "a%00b", "%FF",
+    ] {
+        assert_eq!(parse(value), Err(*value));
+    }
+}
@@ -0,0 +1,59 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+
+use rocket::request::{self, Request, FromRequest};
+
+struct Params(String);
+
+#[rocket::async_trait]
+impl<'a, 'r> FromRequest<'a, 'r> for Params {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let params: Vec<_> = req.matched_params().into_iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+
+        request::Outcome::Success(Params(params.join(", ")))
+    }
+}
+
+#[get("/<name>/static/<_>/<rest..>?<lang>&<q>&flag")]
+fn params(name: String, rest: PathBuf, lang: Option<String>, q: String, params: Params) -> String {
+    let _ = (name, rest, lang, q);
+    params.0
+}
+
+#[get("/")]
+fn none(params: Params) -> String {
+    params.0
+}
+
+mod request_matched_params_tests {
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::ignite().mount("/base", routes![super::params, super::none]);
+        Client::tracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn matched_params_are_enumerated() {
+        let client = client();
+        let response = client.get("/base/bob/static/ignored/a/b%20c?q=x%20y&flag&lang=en")
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "name=bob, rest=a/b%20c, lang=en, q=x%20y");
+
+        let response = client.get("/base/bob/static/ignored/a?flag&q=1").dispatch();
+        assert_eq!(response.into_string().unwrap(), "name=bob, rest=a, q=1");
+    }
+
+    #[test]
+    fn static_route_has_no_params() {
+        let client = client();
+        let response = client.get("/base").dispatch();
+        assert_eq!(response.into_string().unwrap(), "");
+    }
+}
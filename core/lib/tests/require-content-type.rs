@@ -0,0 +1,101 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::ContentType;
+use rocket::request::Form;
+use rocket::data::{RequireContentType, RequiredContentType};
+
+struct FormBody;
+
+impl RequiredContentType for FormBody {
+    fn content_type() -> ContentType {
+        ContentType::Form
+    }
+}
+
+#[derive(FromForm)]
+struct Simple {
+    value: String
+}
+
+#[post("/", data = "<form>")]
+fn form(form: RequireContentType<FormBody, Form<Simple>>) -> String {
+    form.into_inner().into_inner().value
+}
+
+#[post("/", rank = 2)]
+fn fallback() -> &'static str {
+    "fallback"
+}
+
+mod require_content_type_tests {
+    use rocket::local::blocking::Client;
+    use rocket::http::{Status, ContentType};
+
+    fn client() -> Client {
+        let rocket = rocket::ignite().mount("/", routes![super::form, super::fallback]);
+        Client::tracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn matching_content_type_delegates() {
+        let client = client();
+        let response = client.post("/")
+            .header(ContentType::Form)
+            .body("value=hi")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string(), Some("hi".into()));
+
+        // Parameters aren't compared.
+        let response = client.post("/")
+            .header(ContentType::with_params("application", "x-www-form-urlencoded",
+                ("charset", "utf-8")))
+            .body("value=hi")
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("hi".into()));
+    }
+
+    #[test]
+    fn inner_failures_are_returned() {
+        let client = client();
+        let response = client.post("/")
+            .header(ContentType::Form)
+            .body("other=hi")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn mismatched_content_type_fails() {
+        let client = client();
+        let response = client.post("/")
+            .header(ContentType::JSON)
+            .body("value=hi")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnsupportedMediaType);
+
+        let response = client.post("/").body("value=hi").dispatch();
+        assert_eq!(response.status(), Status::UnsupportedMediaType);
+    }
+
+    #[test]
+    fn mismatch_message() {
+        use rocket::data::ContentTypeError;
+
+        let error = ContentTypeError::<String>::Mismatch {
+            expected: ContentType::Form,
+            actual: Some(ContentType::JSON),
+        };
+
+        assert_eq!(error.to_string(), "expected Content-Type \
+            `application/x-www-form-urlencoded` but found `application/json`");
+
+        let expected = ContentType::JSON;
+        let error = ContentTypeError::<String>::Mismatch { expected, actual: None };
+        assert_eq!(error.to_string(), "expected Content-Type `application/json` but none was set");
+    }
+}
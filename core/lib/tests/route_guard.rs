@@ -2,13 +2,18 @@
 
 use std::path::{Path, PathBuf};
 use rocket::http::ext::Normalize;
-use rocket::Route;
+use rocket::{Request, Route};
 
 #[get("/<path..>")]
 fn files(route: &Route, path: PathBuf) -> String {
     Path::new(route.base()).join(path).normalized_str().to_string()
 }
 
+#[get("/<_path..>")]
+fn full_uri(request: &Request<'_>, _path: PathBuf) -> String {
+    request.full_uri().to_string()
+}
+
 mod route_guard_tests {
     use super::*;
     use rocket::local::blocking::Client;
@@ -30,4 +35,19 @@ mod route_guard_tests {
         assert_path(&client, "/first/second/b/c");
         assert_path(&client, "/second/a/b/c");
     }
+
+    #[test]
+    fn check_full_uri() {
+        let rocket = rocket::ignite().mount("/full", routes![full_uri]);
+        let client = Client::tracked(rocket).unwrap();
+
+        let res = client.get("/full/a/b?x=1&name=Bob%20Smith").dispatch();
+        assert_eq!(res.into_string().unwrap(), "/full/a/b?x=1&name=Bob%20Smith");
+
+        let res = client.get("//full//a/b/?x=1").dispatch();
+        assert_eq!(res.into_string().unwrap(), "/full/a/b?x=1");
+
+        let res = client.get("/full/a").dispatch();
+        assert_eq!(res.into_string().unwrap(), "/full/a");
+    }
 }
@@ -18,6 +18,16 @@ fn lenient<'r>(form: LenientForm<MyForm<'r>>) -> String {
     form.field.as_str().into()
 }
 
+#[post("/strict/empty", data = "<_form>")]
+fn strict_empty(_form: Form<()>) -> &'static str {
+    "empty"
+}
+
+#[post("/lenient/empty", data = "<_form>")]
+fn lenient_empty(_form: LenientForm<()>) -> &'static str {
+    "ignored"
+}
+
 mod strict_and_lenient_forms_tests {
     use super::*;
     use rocket::local::blocking::Client;
@@ -26,7 +36,8 @@ mod strict_and_lenient_forms_tests {
     const FIELD_VALUE: &str = "just_some_value";
 
     fn client() -> Client {
-        Client::tracked(rocket::ignite().mount("/", routes![strict, lenient])).unwrap()
+        let routes = routes![strict, lenient, strict_empty, lenient_empty];
+        Client::tracked(rocket::ignite().mount("/", routes)).unwrap()
     }
 
     #[test]
@@ -67,4 +78,38 @@ mod strict_and_lenient_forms_tests {
         assert_eq!(response.status(), Status::Ok);
         assert_eq!(response.into_string(), Some(FIELD_VALUE.into()));
     }
+
+    #[test]
+    fn test_empty_forms() {
+        let client = client();
+        for body in &["", "_method=post"] {
+            let response = client.post("/strict/empty")
+                .header(ContentType::Form)
+                .body(body)
+                .dispatch();
+
+            assert_eq!(response.into_string(), Some("empty".into()));
+
+            let response = client.post("/lenient/empty")
+                .header(ContentType::Form)
+                .body(body)
+                .dispatch();
+
+            assert_eq!(response.into_string(), Some("ignored".into()));
+        }
+
+        let response = client.post("/strict/empty")
+            .header(ContentType::Form)
+            .body(format!("field={}", FIELD_VALUE))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+
+        let response = client.post("/lenient/empty")
+            .header(ContentType::Form)
+            .body(format!("field={}", FIELD_VALUE))
+            .dispatch();
+
+        assert_eq!(response.into_string(), Some("ignored".into()));
+    }
 }
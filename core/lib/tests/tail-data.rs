@@ -0,0 +1,63 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::Tail;
+
+#[post("/", data = "<tail>")]
+fn tail(tail: Tail) -> String {
+    format!("{} {} {}", tail.total(), tail.is_truncated(), String::from_utf8_lossy(&tail))
+}
+
+mod tail_data_tests {
+    use rocket::local::blocking::Client;
+    use rocket::data::{Limits, ToByteUnit};
+
+    fn client(limit: Option<u64>) -> Client {
+        let mut config = rocket::Config::figment();
+        if let Some(limit) = limit {
+            config = config.merge(("limits", Limits::default().limit("tail", limit.into())));
+        }
+
+        Client::tracked(rocket::custom(config).mount("/", routes![super::tail])).unwrap()
+    }
+
+    #[test]
+    fn only_the_last_bytes_are_retained() {
+        let client = client(Some(16));
+        let body: String = (0..100_000).map(|i| ((i % 26) as u8 + b'a') as char).collect();
+        let response = client.post("/").body(&body).dispatch();
+        let expected = format!("100000 true {}", &body[(body.len() - 16)..]);
+        assert_eq!(response.into_string().unwrap(), expected);
+    }
+
+    #[test]
+    fn short_bodies_are_retained_entirely() {
+        let client = client(Some(16));
+        let response = client.post("/").body("short body").dispatch();
+        assert_eq!(response.into_string().unwrap(), "10 false short body");
+
+        let response = client.post("/").body("exactly sixteen!").dispatch();
+        assert_eq!(response.into_string().unwrap(), "16 false exactly sixteen!");
+
+        let response = client.post("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "0 false ");
+    }
+
+    #[test]
+    fn retained_bytes_are_bounded() {
+        // The default retains 64KiB.
+        let client = client(None);
+        let body = "x".repeat(100 * 1024);
+        let response = client.post("/").body(&body).dispatch();
+        let string = response.into_string().unwrap();
+        assert!(string.starts_with("102400 true "));
+        assert_eq!(string.len(), "102400 true ".len() + 64 * 1024);
+
+        // Larger limits are capped at `Tail::MAX`.
+        let client = client(Some(4.mebibytes().as_u64()));
+        let body = "y".repeat(2 * 1024 * 1024);
+        let response = client.post("/").body(&body).dispatch();
+        let string = response.into_string().unwrap();
+        assert!(string.starts_with("2097152 true "));
+        assert_eq!(string.len(), "2097152 true ".len() + 1024 * 1024);
+    }
+}
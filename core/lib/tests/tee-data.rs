@@ -0,0 +1,122 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+
+use rocket::request::{Form, Request};
+use rocket::data::{self, Archive, Data, FromData, Tee, ToByteUnit};
+
+/// Counts the words in the request body.
+struct Words(usize);
+
+#[rocket::async_trait]
+impl FromData for Words {
+    type Error = std::io::Error;
+
+    async fn from_data(_: &Request<'_>, data: Data) -> data::Outcome<Self, Self::Error> {
+        match data.open(1.mebibytes()).stream_to_string().await {
+            Ok(string) => data::Outcome::Success(Words(string.split_whitespace().count())),
+            Err(e) => data::Outcome::Failure((rocket::http::Status::BadRequest, e)),
+        }
+    }
+}
+
+#[derive(FromForm)]
+struct Order {
+    item: String,
+}
+
+#[post("/words", data = "<words>")]
+fn words(words: Tee<Words>) -> String {
+    format!("{} {} {}", words.0, words.size(), words.path().display())
+}
+
+#[post("/order", data = "<order>")]
+fn order(order: Tee<Form<Order>>) -> String {
+    format!("{}\n{}", order.path().display(), order.item)
+}
+
+/// Archives to a fixed path so that tests can check for its removal.
+struct Fixed;
+
+impl Archive for Fixed {
+    fn path(_: &Request<'_>) -> PathBuf {
+        fixed_path()
+    }
+}
+
+fn fixed_path() -> PathBuf {
+    std::env::temp_dir().join("rocket-tee-data-nested-test")
+}
+
+#[post("/nested", data = "<words>")]
+fn nested(words: Tee<Tee<Words>, Fixed>) -> String {
+    words.0.to_string()
+}
+
+mod tee_data_tests {
+    use std::path::PathBuf;
+
+    use rocket::local::blocking::Client;
+    use rocket::http::{Status, ContentType};
+    use rocket::data::Limits;
+
+    fn client(limit: u64) -> Client {
+        let limits = Limits::default().limit("tee", limit.into());
+        let config = rocket::Config::figment().merge(("limits", limits));
+        let routes = routes![super::words, super::order, super::nested];
+        let rocket = rocket::custom(config).mount("/", routes);
+        Client::tracked(rocket).unwrap()
+    }
+
+    #[test]
+    fn body_is_parsed_and_archived() {
+        let client = client(1024);
+        let body = "the quick brown fox\njumps over the lazy dog";
+        let response = client.post("/words").body(body).dispatch();
+        let string = response.into_string().unwrap();
+        let mut parts = string.splitn(3, ' ');
+        assert_eq!(parts.next(), Some("9"));
+        assert_eq!(parts.next(), Some(&*body.len().to_string()));
+
+        let path = PathBuf::from(parts.next().unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), body);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn transformed_guards_are_archived() {
+        let client = client(1024);
+        let response = client.post("/order")
+            .header(ContentType::Form)
+            .body("item=fancy+hat")
+            .dispatch();
+
+        let string = response.into_string().unwrap();
+        let (path, item) = string.split_at(string.find('\n').unwrap());
+        assert_eq!(item, "\nfancy hat");
+
+        let path = PathBuf::from(path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "item=fancy+hat");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn nested_tees_fail_and_clean_up() {
+        let client = client(1024);
+        let response = client.post("/nested").body("two words").dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        assert!(!super::fixed_path().exists());
+    }
+
+    #[test]
+    fn archive_is_limited() {
+        let client = client(8);
+        let response = client.post("/words").body("just nine").dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        let response = client.post("/words").body("eight by").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        let string = response.into_string().unwrap();
+        std::fs::remove_file(string.splitn(3, ' ').nth(2).unwrap()).unwrap();
+    }
+}
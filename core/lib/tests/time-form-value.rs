@@ -0,0 +1,120 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::{Form, FormItems, FromForm, FromFormValue};
+use rocket::request::OwnedFormParseError::BadValue;
+use time::{Date, Time, UtcOffset, OffsetDateTime, PrimitiveDateTime};
+
+fn parse(value: &str) -> Result<Time, &str> {
+    Time::from_form_value(value.into()).map_err(|e| e.as_str())
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Appointment {
+    name: String,
+    at: Time,
+}
+
+fn parse_offset(value: &str) -> Result<OffsetDateTime, &str> {
+    OffsetDateTime::from_form_value(value.into()).map_err(|e| e.as_str())
+}
+
+#[derive(Debug, PartialEq, FromForm)]
+struct Meeting {
+    topic: String,
+    starts: OffsetDateTime,
+}
+
+fn datetime(hour: u8, minute: u8, second: u8, offset: UtcOffset) -> OffsetDateTime {
+    Date::try_from_ymd(2021, 3, 1).unwrap()
+        .try_with_hms(hour, minute, second).unwrap()
+        .assume_offset(offset)
+}
+
+#[test]
+fn times_of_day() {
+    assert_eq!(parse("14:30"), Ok(Time::try_from_hms(14, 30, 0).unwrap()));
+    assert_eq!(parse("14:30:05"), Ok(Time::try_from_hms(14, 30, 5).unwrap()));
+    assert_eq!(parse("00:00"), Ok(Time::midnight()));
+    assert_eq!(parse("23%3A59%3A59"), Ok(Time::try_from_hms(23, 59, 59).unwrap()));
+}
+
+#[test]
+fn invalid_times_fail() {
+    for value in &["", "14", "14:", "24:00", "14:60", "14:30:61", "14:30x", "14:30:05 PM", "%FF"] {
+        assert_eq!(parse(value), Err(*value), "{:?}", value);
+    }
+}
+
+#[test]
+fn time_fields() {
+    let form = Form::<Appointment>::parse_pairs(vec![("name", "Dentist"), ("at", "09:15")]);
+    let at = Time::try_from_hms(9, 15, 0).unwrap();
+    assert_eq!(form, Ok(Appointment { name: "Dentist".into(), at }));
+
+    let form = Form::<Appointment>::parse_pairs(vec![("name", "Dentist"), ("at", "9:15am")]);
+    assert_eq!(form, Err(BadValue("at".into(), "9:15am".into())));
+}
+
+#[test]
+fn offset_datetimes() {
+    let east = UtcOffset::east_hours(2);
+    let west = UtcOffset::west_seconds(5 * 3600 + 30 * 60);
+    assert_eq!(parse_offset("2021-03-01T14:30:00+02:00"), Ok(datetime(14, 30, 0, east)));
+    assert_eq!(parse_offset("2021-03-01T14:30:00-05:30"), Ok(datetime(14, 30, 0, west)));
+    assert_eq!(parse_offset("2021-03-01T12:30:00Z"), Ok(datetime(12, 30, 0, UtcOffset::UTC)));
+    assert_eq!(parse_offset("2021-03-01T12:30:00z"), Ok(datetime(12, 30, 0, UtcOffset::UTC)));
+    assert_eq!(parse_offset("2021-03-01T14%3A30%3A00%2B02%3A00"), Ok(datetime(14, 30, 0, east)));
+
+    // The same instant in different offsets.
+    assert_eq!(parse_offset("2021-03-01T14:30:00+02:00"), parse_offset("2021-03-01T12:30:00Z"));
+}
+
+#[test]
+fn invalid_offset_datetimes_fail() {
+    let values = &[
+        "", "2021-03-01T14:30:00", "2021-03-01T14:30", "2021-03-01", "2021-03-01T14:30+02:00",
+        "2021-03-01T14:30:00+0200", "2021-03-01T14:30:00+02", "2021-03-01 14:30:00Z",
+        "2021-03-01T14:30:00.5Z", "2021-02-30T14:30:00Z",
+        "2021-03-01T14:30:00ZZ", "x2021-03-01T14:30:00Z", "%FF",
+    ];
+
+    for value in values {
+        assert_eq!(parse_offset(value), Err(*value), "{:?}", value);
+    }
+}
+
+#[test]
+fn offset_datetime_fields() {
+    // A `+` that isn't percent-encoded is decoded as a space.
+    let form = "topic=Standup&starts=2021-03-01T14%3A30%3A00%2B02%3A00";
+    let meeting = Meeting::from_form(&mut FormItems::from(form), true);
+    let starts = datetime(14, 30, 0, UtcOffset::east_hours(2));
+    assert_eq!(meeting, Ok(Meeting { topic: "Standup".into(), starts }));
+
+    let form = "topic=Standup&starts=2021-03-01T14:30:00+02:00";
+    assert!(Meeting::from_form(&mut FormItems::from(form), true).is_err());
+
+    let pairs = vec![("topic", "Standup"), ("starts", "2021-03-01T14:30:00")];
+    let form = Form::<Meeting>::parse_pairs(pairs);
+    assert_eq!(form, Err(BadValue("starts".into(), "2021-03-01T14:30:00".into())));
+}
+
+#[test]
+fn dates_and_local_datetimes() {
+    let date = |value: &'static str| Date::from_form_value(value.into()).map_err(|e| e.as_str());
+    let march_first = Date::try_from_ymd(2021, 3, 1).unwrap();
+    assert_eq!(date("2021-03-01"), Ok(march_first));
+    for value in &["", "2021-3-1", "2021-03-01T14:30", "2021-02-30", "03/01/2021", "2021-03-01x"] {
+        assert_eq!(date(value), Err(*value), "{:?}", value);
+    }
+
+    let local = |value: &'static str| {
+        PrimitiveDateTime::from_form_value(value.into()).map_err(|e| e.as_str())
+    };
+
+    assert_eq!(local("2021-03-01T14:30"), Ok(march_first.try_with_hms(14, 30, 0).unwrap()));
+    assert_eq!(local("2021-03-01T14%3A30%3A05"), Ok(march_first.try_with_hms(14, 30, 5).unwrap()));
+    for value in &["", "2021-03-01", "2021-03-01 14:30", "2021-03-01T14:30Z", "2021-03-01T25:00"] {
+        assert_eq!(local(value), Err(*value), "{:?}", value);
+    }
+}
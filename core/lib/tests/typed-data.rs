@@ -0,0 +1,150 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::ContentType;
+use rocket::data::{Typed, TypedError, DataSpec, RequiredContentType, ByteUnit};
+
+struct Csv;
+
+impl RequiredContentType for Csv {
+    fn content_type() -> ContentType {
+        ContentType::new("text", "csv")
+    }
+}
+
+impl DataSpec for Csv {
+    const LIMIT: &'static str = "csv";
+    const DEFAULT_LIMIT: ByteUnit = ByteUnit::Byte(16);
+}
+
+#[post("/", data = "<csv>")]
+fn import(csv: Result<Typed<Csv, String>, TypedError<std::io::Error>>) -> String {
+    match csv {
+        Ok(csv) => format!("{} rows: {}", csv.lines().count(), csv.into_inner()),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[post("/strict", data = "<csv>")]
+fn strict(csv: Typed<Csv, String>) -> String {
+    csv.into_inner()
+}
+
+#[post("/", rank = 2)]
+fn fallback() -> &'static str {
+    "fallback"
+}
+
+mod typed_data_tests {
+    use rocket::local::blocking::Client;
+    use rocket::data::Limits;
+    use rocket::http::{ContentType, Header, Status};
+
+    fn client(limit: Option<u64>) -> Client {
+        let mut limits = Limits::default();
+        if let Some(limit) = limit {
+            limits = limits.limit("csv", limit.into());
+        }
+
+        let config = rocket::Config::figment().merge(("limits", limits));
+        let routes = routes![super::import, super::strict, super::fallback];
+        Client::tracked(rocket::custom(config).mount("/", routes)).unwrap()
+    }
+
+    fn csv() -> ContentType {
+        ContentType::new("text", "csv")
+    }
+
+    #[test]
+    fn matching_content_type_delegates() {
+        let client = client(None);
+        let response = client.post("/").header(csv()).body("a,b\nc,d").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "2 rows: a,b\nc,d");
+
+        // Parameters aren't compared.
+        let response = client.post("/")
+            .header(ContentType::parse_flexible("text/csv; charset=utf-8").unwrap())
+            .body("a,b")
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "1 rows: a,b");
+    }
+
+    #[test]
+    fn mismatched_content_type_forwards() {
+        let client = client(None);
+        let response = client.post("/").header(ContentType::JSON).body("a,b").dispatch();
+        assert_eq!(response.into_string().unwrap(), "fallback");
+
+        let response = client.post("/").body("a,b").dispatch();
+        assert_eq!(response.into_string().unwrap(), "fallback");
+
+        // The content type is checked before the limit.
+        let response = client.post("/")
+            .header(ContentType::JSON)
+            .header(Header::new("Content-Length", "1000"))
+            .body("a,b")
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "fallback");
+    }
+
+    #[test]
+    fn declared_length_over_limit_fails() {
+        let body = "a,b\nc,d\ne,f\ng,h\ni,j";
+        let request = |client: &Client, length: usize| {
+            client.post("/")
+                .header(csv())
+                .header(Header::new("Content-Length", length.to_string()))
+                .body(body)
+                .dispatch()
+        };
+
+        // The default limit, 16 bytes, applies when none is configured.
+        let default = client(None);
+        let response = request(&default, body.len());
+        assert_eq!(response.into_string().unwrap(), "body length (19) exceeds limit (16B)");
+
+        let custom = client(Some(64));
+        let response = request(&custom, body.len());
+        assert_eq!(response.into_string().unwrap(), format!("5 rows: {}", body));
+
+        let response = request(&custom, 65);
+        assert_eq!(response.into_string().unwrap(), "body length (65) exceeds limit (64B)");
+
+        let response = default.post("/strict")
+            .header(csv())
+            .header(Header::new("Content-Length", "17"))
+            .body(body)
+            .dispatch();
+
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn undeclared_length_over_limit_fails() {
+        // The body is rejected, not truncated, even without a `Content-Length`.
+        let client = client(Some(8));
+        let response = client.post("/").header(csv()).body("a,b\nc,d\ne,f").dispatch();
+        assert_eq!(response.into_string().unwrap(), "body length (9) exceeds limit (8B)");
+
+        let response = client.post("/strict").header(csv()).body("a,b\nc,d\ne,f").dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+
+        // A body exactly at the limit is accepted.
+        let response = client.post("/").header(csv()).body("a,b\nc,d").dispatch();
+        assert_eq!(response.into_string().unwrap(), "2 rows: a,b\nc,d");
+    }
+
+    #[test]
+    fn malformed_length_fails() {
+        let client = client(None);
+        let response = client.post("/strict")
+            .header(csv())
+            .header(Header::new("Content-Length", "many"))
+            .body("a,b")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(feature = "uuid")]
+#[macro_use] extern crate rocket;
+
+#[cfg(feature = "uuid")]
+mod uuid_form_value {
+    use uuid::Uuid;
+    use rocket::local::blocking::Client;
+    use rocket::request::{Form, FromFormValue, OwnedFormParseError::BadValue};
+
+    const ID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Transfer {
+        from: Uuid,
+        to: Option<Uuid>,
+    }
+
+    #[get("/<id>")]
+    fn resource(id: Uuid) -> String {
+        id.to_string()
+    }
+
+    fn parse(value: &str) -> Result<Uuid, &str> {
+        Uuid::from_form_value(value.into()).map_err(|e| e.as_str())
+    }
+
+    #[test]
+    fn canonical_uuids_parse() {
+        let id = Uuid::parse_str(ID).unwrap();
+        assert_eq!(parse(ID), Ok(id));
+        assert_eq!(parse(&ID.to_uppercase()), Ok(id));
+        assert_eq!(parse("00000000-0000-0000-0000-000000000000"), Ok(Uuid::nil()));
+
+        let form = Form::<Transfer>::parse_pairs(vec![("from", ID), ("to", "nope")]);
+        assert_eq!(form, Ok(Transfer { from: id, to: None }));
+
+        let form = Form::<Transfer>::parse_pairs(vec![("from", "nope")]);
+        assert_eq!(form, Err(BadValue("from".into(), "nope".into())));
+    }
+
+    #[test]
+    fn other_forms_fail() {
+        let simple = ID.replace('-', "");
+        let braced = format!("{{{}}}", ID);
+        let urn = format!("urn:uuid:{}", ID);
+        let encoded = ID.replace('-', "%2D");
+        let misplaced = format!("{}-{}", &simple[..9], &ID[10..]);
+        let values = &[
+            "", "67e55044", &simple, &braced, &urn, &encoded, &misplaced, &ID[1..],
+            "67e55044-10b1-426f-9247-bb680e5fe0cg", "67e55044+10b1-426f-9247-bb680e5fe0c8",
+        ];
+
+        for value in values {
+            assert_eq!(parse(value), Err(*value), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn uuid_params() {
+        let client = Client::tracked(rocket::ignite().mount("/", routes![resource])).unwrap();
+        let response = client.get(format!("/{}", ID)).dispatch();
+        assert_eq!(response.into_string().unwrap(), ID);
+
+        let response = client.get(format!("/{}", ID.replace('-', ""))).dispatch();
+        assert_eq!(response.status(), rocket::http::Status::NotFound);
+    }
+}
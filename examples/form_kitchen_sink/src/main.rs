@@ -29,9 +29,12 @@ fn sink(sink: Result<Form<FormInput<'_>>, FormError<'_>>) -> String {
     match sink {
         Ok(form) => format!("{:?}", &*form),
         Err(FormDataError::Io(_)) => format!("Form input was invalid UTF-8."),
+        Err(FormDataError::TooLarge(n)) => format!("Form input is too large: {} bytes.", n),
+        Err(FormDataError::Incomplete(n, m)) => format!("Form input ended after {}/{} bytes.", n, m),
         Err(FormDataError::Malformed(f)) | Err(FormDataError::Parse(_, f)) => {
             format!("Invalid form input: {}", f)
         }
+        Err(e) => format!("Form input was rejected: {}.", e),
     }
 }
 
@@ -112,6 +112,10 @@ elif [ "$1" = "--core" ]; then
   FEATURES=(
     secrets
     tls
+    bigint
+    uuid
+    regex
+    chrono
   )
 
   pushd "${CORE_LIB_ROOT}" > /dev/null 2>&1